@@ -50,6 +50,123 @@ pub struct Args {
     #[clap(value_parser)]
     pub ignore_matches: Option<Vec<String>>,
 
+    /// Comma separated names of specials to enable.
+    #[clap(long = "specials")]
+    #[clap(help = "Comma separated names of specials to enable")]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_parser)]
+    pub specials: Option<Vec<String>>,
+
+    /// Comma separated names of packages under node_modules to check as a part of the directory.
+    #[clap(long = "include-modules")]
+    #[clap(help = "Comma separated names of packages under node_modules to check")]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_parser)]
+    pub include_modules: Option<Vec<String>>,
+
+    /// A flag to indicate if depcheck checks files sequentially in the current thread.
+    #[clap(long = "single-threaded")]
+    #[clap(help = "A flag to indicate if depcheck checks files in a single thread")]
+    #[clap(value_parser)]
+    pub single_threaded: bool,
+
+    /// A flag to indicate if depcheck follows symbolic links inside of the directory.
+    #[clap(long = "follow-symlinks")]
+    #[clap(help = "A flag to indicate if depcheck follows symbolic links inside of the directory")]
+    #[clap(value_parser)]
+    pub follow_symlinks: bool,
+
+    /// A flag to indicate if depcheck treats common dev tools in dev dependencies as used.
+    #[clap(long = "suppress-common-devtools")]
+    #[clap(help = "A flag to indicate if depcheck treats common dev tools as used")]
+    #[clap(value_parser)]
+    pub suppress_common_devtools: bool,
+
+    /// Comma separated extensions of templates whose inline scripts are checked.
+    #[clap(long = "embedded-script-extensions")]
+    #[clap(help = "Comma separated extensions of templates whose inline scripts are checked")]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_parser)]
+    pub embedded_script_extensions: Option<Vec<String>>,
+
+    /// Path to a package-lock.json whose direct dependencies are checked instead of package.json.
+    #[clap(long = "lockfile")]
+    #[clap(help = "Path to a package-lock.json whose direct dependencies are checked")]
+    #[clap(takes_value = true)]
+    #[clap(value_parser = ValueParser::os_string())]
+    pub lockfile: Option<PathBuf>,
+
+    /// Comma separated overlay manifests whose dependency sections are merged into package.json.
+    #[clap(long = "manifest-paths")]
+    #[clap(help = "Comma separated overlay manifests whose dependencies are merged")]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_parser = ValueParser::os_string())]
+    pub manifest_paths: Option<Vec<PathBuf>>,
+
+    /// A flag to indicate if depcheck outputs absolute file paths.
+    #[clap(long = "absolute-paths")]
+    #[clap(help = "A flag to indicate if depcheck outputs absolute file paths")]
+    #[clap(value_parser)]
+    pub absolute_paths: bool,
+
+    /// Comma separated workspace packages whose dependencies are hoisted to the directory.
+    #[clap(long = "hoisted-scopes")]
+    #[clap(help = "Comma separated workspace packages whose dependencies are hoisted")]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_parser)]
+    pub hoisted_scopes: Option<Vec<String>>,
+
+    /// Comma separated package.json fields whose values are package names treated as used.
+    #[clap(long = "manifest-package-fields")]
+    #[clap(help = "Comma separated package.json fields whose values are used package names")]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_parser)]
+    pub manifest_package_fields: Option<Vec<String>>,
+
+    /// Comma separated extensions of files to scan, other files are skipped while walking.
+    #[clap(long = "scan-extensions")]
+    #[clap(help = "Comma separated extensions of files to scan, other files are skipped")]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_parser)]
+    pub scan_extensions: Option<Vec<String>>,
+
+    /// Comma separated scopes whose packages aren't missing while they aren't installed.
+    #[clap(long = "unresolved-internal-scopes")]
+    #[clap(help = "Comma separated scopes whose uninstalled packages aren't reported as missing")]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_parser)]
+    pub unresolved_internal_scopes: Option<Vec<String>>,
+
+    /// A flag to indicate if depcheck scans only files tracked by git.
+    #[clap(long = "git-tracked-only")]
+    #[clap(help = "A flag to indicate if depcheck scans only files tracked by git")]
+    #[clap(value_parser)]
+    pub git_tracked_only: bool,
+
+    /// A flag to indicate if depcheck scans hidden files and directories.
+    #[clap(long = "scan-hidden")]
+    #[clap(help = "A flag to indicate if depcheck scans hidden files and directories")]
+    #[clap(value_parser)]
+    pub scan_hidden: bool,
+
+    /// A flag to indicate if depcheck fails when a file fails to parse.
+    #[clap(long = "fail-on-parse-error")]
+    #[clap(help = "A flag to indicate if depcheck fails when a file fails to parse")]
+    #[clap(value_parser)]
+    pub fail_on_parse_error: bool,
+
+    /// A flag to indicate if depcheck checks projects referenced by tsconfig references as well.
+    #[clap(long = "follow-project-references")]
+    #[clap(help = "A flag to indicate if depcheck checks projects referenced by tsconfig as well")]
+    #[clap(value_parser)]
+    pub follow_project_references: bool,
+
+    /// A flag to indicate if depcheck checks the directory again whenever its files change.
+    #[clap(long = "watch")]
+    #[clap(help = "A flag to indicate if depcheck checks again whenever files change")]
+    #[clap(value_parser)]
+    pub watch: bool,
+
     /// logging level
     #[clap(flatten)]
     pub verbose: clap_verbosity_flag::Verbosity,
@@ -3,7 +3,8 @@ mod args;
 use crate::args::Args;
 use clap::Parser;
 use depckeck_rs_core::checker::Checker;
-use depckeck_rs_core::config::Config;
+use depckeck_rs_core::checker_result::to_text;
+use depckeck_rs_core::config::{Config, DependencySource, PathBase};
 use proc_exit::WithCodeResultExt;
 
 pub fn run() {
@@ -32,6 +33,24 @@ fn try_run() -> proc_exit::ExitResult {
         ignore_path,
         ignore_patterns,
         ignore_matches,
+        specials,
+        include_modules,
+        single_threaded,
+        follow_symlinks,
+        suppress_common_devtools,
+        embedded_script_extensions,
+        lockfile,
+        manifest_paths,
+        absolute_paths,
+        hoisted_scopes,
+        manifest_package_fields,
+        scan_extensions,
+        unresolved_internal_scopes,
+        git_tracked_only,
+        scan_hidden,
+        fail_on_parse_error,
+        follow_project_references,
+        watch,
         verbose,
     } = args;
 
@@ -42,7 +61,14 @@ fn try_run() -> proc_exit::ExitResult {
     let mut config = Config::new(directory)
         .with_ignore_bin_package(ignore_bin_package)
         .with_skip_missing(skip_missing)
-        .with_ignore_path(ignore_path);
+        .with_ignore_path(ignore_path)
+        .with_single_threaded(single_threaded)
+        .with_follow_symlinks(follow_symlinks)
+        .with_suppress_common_devtools(suppress_common_devtools)
+        .with_git_tracked_only(git_tracked_only)
+        .with_scan_hidden(scan_hidden)
+        .with_fail_on_parse_error(fail_on_parse_error)
+        .with_follow_project_references(follow_project_references);
 
     if let Some(ignore_patterns) = ignore_patterns {
         config = config.with_ignore_patterns(ignore_patterns);
@@ -52,11 +78,72 @@ fn try_run() -> proc_exit::ExitResult {
         config = config.with_ignore_matches(ignore_matches);
     }
 
-    let result = Checker::new(config)
+    if let Some(specials) = specials {
+        config = config.with_specials(specials);
+    }
+
+    if let Some(include_modules) = include_modules {
+        config = config.with_include_modules(include_modules);
+    }
+
+    if let Some(embedded_script_extensions) = embedded_script_extensions {
+        config = config.with_embedded_script_extensions(embedded_script_extensions);
+    }
+
+    if let Some(lockfile) = lockfile {
+        config = config.with_dependency_source(DependencySource::Lockfile(lockfile));
+    }
+
+    if let Some(manifest_paths) = manifest_paths {
+        config = config.with_manifest_paths(manifest_paths);
+    }
+
+    if absolute_paths {
+        config = config.with_path_base(PathBase::Absolute);
+    }
+
+    if let Some(hoisted_scopes) = hoisted_scopes {
+        config = config.with_hoisted_scopes(hoisted_scopes);
+    }
+
+    if let Some(manifest_package_fields) = manifest_package_fields {
+        config = config.with_manifest_package_fields(manifest_package_fields);
+    }
+
+    if let Some(scan_extensions) = scan_extensions {
+        config = config.with_scan_extensions(Some(scan_extensions.into_iter().collect()));
+    }
+
+    if let Some(unresolved_internal_scopes) = unresolved_internal_scopes {
+        config = config.with_unresolved_internal_scopes(unresolved_internal_scopes);
+    }
+
+    let checker = Checker::new(config);
+
+    if watch {
+        return checker
+            .watch(|result| print!("{}", to_text(&result)))
+            .with_code(proc_exit::Code::USAGE_ERR);
+    }
+
+    if follow_project_references {
+        let results = checker
+            .check_projects()
+            .with_code(proc_exit::Code::USAGE_ERR)?;
+
+        for (directory, result) in results {
+            println!("{}", directory.display());
+            print!("{}", to_text(&result));
+        }
+
+        return Ok(());
+    }
+
+    let result = checker
         .check_package()
         .with_code(proc_exit::Code::USAGE_ERR)?;
 
-    println!("{:#?}", result);
+    print!("{}", to_text(&result));
 
     Ok(())
 }
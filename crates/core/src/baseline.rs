@@ -0,0 +1,135 @@
+use crate::error::DepcheckError;
+use crate::util::strip_jsonc::strip_jsonc;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Known findings suppressed from a checker result, read from `.depcheck-baseline.json`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Baseline {
+    /// Suppressed missing dependencies.
+    #[serde(default)]
+    pub missing: Vec<BaselineEntry>,
+    /// Suppressed unused dependencies.
+    #[serde(default)]
+    pub unused: Vec<BaselineEntry>,
+    /// Suppressed unused dev dependencies.
+    #[serde(default)]
+    pub unused_dev: Vec<BaselineEntry>,
+}
+
+/// A suppressed finding.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineEntry {
+    /// The dependency name.
+    pub name: String,
+    /// The optional last day of suppression formatted as `YYYY-MM-DD`, other formats fail
+    /// to deserialize.
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub until: Option<String>,
+}
+
+impl Baseline {
+    /// Deserializes a `Baseline` from a file path.
+    pub fn from_path<T: AsRef<Path>>(path: T) -> Result<Self, DepcheckError> {
+        let path = path.as_ref();
+        let content = fs::read(path).map_err(|error| DepcheckError::io(path, error))?;
+        Self::from_slice(content.as_slice())
+            .map_err(|error| DepcheckError::parse_manifest(path, error))
+    }
+
+    /// Deserializes a `Baseline` from bytes, allowing comments and trailing commas.
+    pub fn from_slice(v: &[u8]) -> serde_json::Result<Self> {
+        let content = String::from_utf8_lossy(v);
+        serde_json::from_str(&strip_jsonc(&content))
+    }
+}
+
+impl BaselineEntry {
+    /// Check if the suppression date has passed, `today` is formatted as `YYYY-MM-DD`.
+    ///
+    /// Zero padded dates are ordered like their strings.
+    pub fn is_expired(&self, today: &str) -> bool {
+        self.until.as_deref().map_or(false, |until| until < today)
+    }
+}
+
+/// Deserialize an optional date, rejecting a date which isn't a zero padded `YYYY-MM-DD`.
+fn deserialize_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    let date = Option::<String>::deserialize(deserializer)?;
+
+    match date {
+        Some(date) if !is_date(&date) => Err(D::Error::custom(format!(
+            "invalid date {date:?}, expected YYYY-MM-DD"
+        ))),
+        date => Ok(date),
+    }
+}
+
+/// Check if a string is a zero padded `YYYY-MM-DD` date of an existing month and day.
+fn is_date(date: &str) -> bool {
+    let parts = date.split('-').collect::<Vec<_>>();
+
+    let is_padded = parts.iter().map(|part| part.len()).eq([4, 2, 2])
+        && parts
+            .iter()
+            .all(|part| part.bytes().all(|byte| byte.is_ascii_digit()));
+
+    if !is_padded {
+        return false;
+    }
+
+    let numbers = parts
+        .iter()
+        .filter_map(|part| part.parse::<u32>().ok())
+        .collect::<Vec<_>>();
+
+    let (year, month, day) = match numbers.as_slice() {
+        [year, month, day] => (*year, *month, *day),
+        _ => return false,
+    };
+
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        2 if is_leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return false,
+    };
+
+    (1..=days_in_month).contains(&day)
+}
+
+/// Get the current UTC date formatted as `YYYY-MM-DD`.
+pub fn today() -> String {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+
+    // Converts days since the epoch to a civil date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
@@ -1,41 +1,141 @@
-use eyre::WrapErr;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use ignore::overrides::OverrideBuilder;
 use ignore::{self, WalkBuilder};
+use notify::{Event, RecursiveMode, Watcher};
 use relative_path::RelativePathBuf;
 use swc_common::comments::SingleThreadedComments;
-use swc_ecma_dep_graph::analyze_dependencies;
+use swc_common::SourceFile;
+use swc_ecma_ast::Module;
+use swc_ecma_dep_graph::{analyze_dependencies, DependencyKind};
+use swc_ecma_parser::Syntax;
 
-use crate::checker_result::CheckerResult;
-use crate::config::Config;
+#[cfg(feature = "stream")]
+use futures_core::Stream;
+
+use crate::baseline::today;
+use crate::checker_result::{CheckerResult, DynamicUsage, SpecialReport};
+use crate::config::{Config, DependencySource, PathBase};
+use crate::create_require::collect_created_requires;
+use crate::css::{collect_css_specifiers, collect_less_specifiers, is_css_file, is_less_file};
+use crate::dead_imports::collect_dead_imports;
 use crate::dependency::Dependency;
-use crate::package::Package;
-use crate::parser::Parser;
+use crate::dynamic_usage::{
+    collect_concatenated_specifiers, collect_dynamic_specifiers, collect_dynamic_usage,
+};
+use crate::embedded_script::{collect_embedded_scripts, is_embedded_script_file};
+use crate::error::DepcheckError;
+use crate::legacy_modules::collect_legacy_module_specifiers;
+use crate::loader_hooks::collect_registered_loaders;
+use crate::lockfile::LockedDependencies;
+use crate::manifest_fields::collect_manifest_field_packages;
+use crate::module_augmentation::collect_augmented_modules;
+use crate::package::{DepsSet, Package};
+use crate::parser::{is_declaration_file, Parser};
+use crate::special::{get_specials, Special, SpecialContext};
+use crate::use_directive::collect_use_directives;
+use crate::util::collect_project_references::collect_project_references;
+use crate::util::is_bin_dependency::BinDependencies;
 use crate::util::is_module::is_module;
+use crate::util::load_baseline::{load_baseline, BASELINE_FILE};
+use crate::util::load_git_tracked_files::load_git_tracked_files;
 use crate::util::load_module::load_module;
-use std::path::PathBuf;
+use crate::util::load_tsconfig::load_tsconfig;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
-use std::sync::mpsc::{Receiver, Sender};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// A callback getting possible package names of a dynamic import from its argument source text.
+pub type DynamicResolver = dyn Fn(&str) -> Vec<String> + Send + Sync;
+
+/// A callback remapping a package name extracted from an import specifier, `None` drops it.
+pub type SpecifierMapper = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+/// A delay without changes of watched files ending a debounce of changes into one check.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// An interval of checking the cancellation flag while watched files don't change.
+const WATCH_CANCELLATION_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Version control directories skipped even when hidden files are scanned.
+const VCS_DIRECTORIES: [&str; 3] = [".git", ".hg", ".svn"];
 
 /// Dependencies checker.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone)]
 pub struct Checker {
     config: Arc<Config>,
     parser: Arc<Parser>,
+    dynamic_resolver: Option<Arc<DynamicResolver>>,
+    specifier_mapper: Option<Arc<SpecifierMapper>>,
+    cancellation: Option<Arc<AtomicBool>>,
 }
 
 impl Checker {
     pub fn new(config: Config) -> Self {
         log::trace!("init checker with config {:#?}", config);
 
+        let parser = Parser::default().with_syntax_features(config.get_syntax_features());
+
         Checker {
             config: Arc::new(config),
-            parser: Default::default(),
+            parser: Arc::new(parser),
+            dynamic_resolver: None,
+            specifier_mapper: None,
+            cancellation: None,
         }
     }
+
+    /// Set a resolver of non literal `import(...)` and `require(...)` arguments like
+    /// `import(`./features/${flag}`)`, returned specifiers are checked as usual imports.
+    pub fn with_dynamic_resolver(mut self, dynamic_resolver: Box<DynamicResolver>) -> Self {
+        self.dynamic_resolver = Some(Arc::from(dynamic_resolver));
+        self
+    }
+
+    /// Set a mapper of package names of import specifiers like `@internal/x` rewritten at build
+    /// time, it's called before declared and used dependencies are compared.
+    /// Returning `None` drops the specifier as an internal module.
+    pub fn with_specifier_mapper(mut self, specifier_mapper: Box<SpecifierMapper>) -> Self {
+        self.specifier_mapper = Some(Arc::from(specifier_mapper));
+        self
+    }
+
+    /// Set a flag cancelling the check once it's set to `true` from another thread,
+    /// a cancelled check stops walking files and returns an error.
+    pub fn with_cancellation(mut self, cancellation: Arc<AtomicBool>) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+}
+
+// callbacks and the cancellation flag can't be compared, so checkers are equal by their
+// config and parser like before callbacks were added
+impl PartialEq for Checker {
+    fn eq(&self, other: &Self) -> bool {
+        self.config == other.config && self.parser == other.parser
+    }
+}
+
+impl Eq for Checker {}
+
+impl fmt::Debug for Checker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Checker")
+            .field("config", &self.config)
+            .field("parser", &self.parser)
+            .field("dynamic_resolver", &self.dynamic_resolver.is_some())
+            .field("specifier_mapper", &self.specifier_mapper.is_some())
+            .field("cancellation", &self.cancellation)
+            .finish()
+    }
 }
 
 pub enum WorkerResult {
@@ -45,85 +145,998 @@ pub enum WorkerResult {
 
 impl Checker {
     /// check dependencies with config and parsers.
-    pub fn check_package(self) -> eyre::Result<CheckerResult> {
+    pub fn check_package(self) -> Result<CheckerResult, DepcheckError> {
+        let context = Arc::new(self.build_context()?);
+        let directory_analysis = self.check_directory(&context)?;
+
+        self.build_result(&context, directory_analysis)
+    }
+
+    /// Build a result of analyzed files, findings listed in the baseline are suppressed.
+    fn build_result(
+        &self,
+        context: &CheckContext,
+        directory_analysis: DirectoryAnalysis,
+    ) -> Result<CheckerResult, DepcheckError> {
+        let directory = self.config.get_directory();
+
+        if self.config.fail_on_parse_error() && !directory_analysis.parse_errors.is_empty() {
+            return Err(DepcheckError::ParseSource {
+                directory: directory.to_path_buf(),
+                files: directory_analysis.parse_errors,
+            });
+        }
+
+        let baseline = load_baseline(directory)?;
+
+        log::debug!("loaded baseline json {:#?}", baseline);
+
+        let result = CheckerResult::new_with_bin_dependencies(
+            directory_analysis.using_dependencies,
+            context.package.clone(),
+            &self.config,
+            &context.bin_dependencies,
+        )
+        .with_import_counts(directory_analysis.import_counts)
+        .with_dynamic_usage(directory_analysis.dynamic_usage)
+        .with_files_scanned(directory_analysis.files_scanned)
+        .with_parse_errors(directory_analysis.parse_errors)
+        .with_reexported_only(directory_analysis.reexported_only)
+        .with_baseline(&baseline, &today());
+
+        Ok(result)
+    }
+
+    /// check dependencies of the directory as if only the declared dependencies were in
+    /// `package.json`, so a dry run of removing dependencies shows what becomes missing.
+    pub fn check_with_dependencies(
+        &self,
+        declared: DepsSet,
+    ) -> Result<CheckerResult, DepcheckError> {
+        let config = Config::clone(&self.config)
+            .with_dependency_source(DependencySource::Explicit(declared));
+        let checker = Checker {
+            config: Arc::new(config),
+            ..self.clone()
+        };
+
+        checker.check_package()
+    }
+
+    /// check dependencies of several independent directories with the same config and parser.
+    ///
+    /// Like workspaces of a monorepo, each directory inherits ignore patterns and flags of the
+    /// config and ignore files of parent directories, while its own ignore file overrides them.
+    pub fn check_packages(
+        self,
+        directories: Vec<PathBuf>,
+    ) -> Result<Vec<(PathBuf, CheckerResult)>, DepcheckError> {
+        directories
+            .into_iter()
+            .map(|directory| {
+                let config = Config::clone(&self.config).with_directory(directory.clone());
+                let checker = Checker {
+                    config: Arc::new(config),
+                    parser: Arc::clone(&self.parser),
+                    dynamic_resolver: self.dynamic_resolver.clone(),
+                    specifier_mapper: self.specifier_mapper.clone(),
+                    cancellation: self.cancellation.clone(),
+                };
+
+                checker.check_package().map(|result| (directory, result))
+            })
+            .collect()
+    }
+
+    /// check dependencies of the directory and, when project references are followed, of every
+    /// project of its tsconfig references graph having a `package.json`.
+    ///
+    /// Packages of the checked projects import each other internally, so they're never missing.
+    /// Referenced projects without a `package.json` are checked as a part of a checked package
+    /// containing them, other ones are reported by warnings of the directory result.
+    pub fn check_projects(self) -> Result<Vec<(PathBuf, CheckerResult)>, DepcheckError> {
+        let directory = self.config.get_directory().to_path_buf();
+        let mut directories = vec![directory.clone()];
+        let mut unchecked_projects = Vec::new();
+
+        if self.config.follow_project_references() {
+            let (packages, projects): (Vec<_>, Vec<_>) = collect_project_references(&directory)
+                .into_iter()
+                .partition(|project| is_module(project));
+            directories.extend(packages);
+
+            let checked_directories = directories
+                .iter()
+                .filter_map(|directory| directory.canonicalize().ok())
+                .collect::<Vec<_>>();
+
+            unchecked_projects = projects
+                .into_iter()
+                .filter_map(|project| project.canonicalize().ok())
+                .filter(|project| {
+                    !checked_directories
+                        .iter()
+                        .any(|directory| project.starts_with(directory))
+                })
+                .collect();
+        }
+
+        let project_names = directories
+            .iter()
+            .filter_map(|directory| load_module(directory).ok())
+            .map(|package| package.name)
+            .filter(|name| !name.is_empty())
+            .collect::<HashSet<_>>();
+
+        let mut results = self.check_packages(directories)?;
+
+        for (_, result) in &mut results {
+            result
+                .missing_dependencies
+                .retain(|dependency, _| !project_names.contains(dependency));
+        }
+
+        // the directory itself is checked first
+        if let Some((_, result)) = results.first_mut() {
+            result.extend_warnings(unchecked_projects.iter().map(|project| {
+                format!(
+                    "referenced project {} has no package json, its files aren't checked",
+                    project.display()
+                )
+            }));
+        }
+
+        Ok(results)
+    }
+
+    /// check dependencies of files and yield dependencies of each file once it's analyzed.
+    ///
+    /// Files are checked in background threads, dependencies are yielded as they're found
+    /// without aggregating them into a result, `package.json` fields are yielded last.
+    /// The stream ends early when it's dropped or the check is cancelled.
+    #[cfg(feature = "stream")]
+    pub fn check_stream(
+        &self,
+    ) -> Result<impl Stream<Item = (RelativePathBuf, HashSet<String>)> + Unpin, DepcheckError> {
+        let context = Arc::new(self.build_context()?);
+        let walkers = self.build_walkers()?;
+
+        let (stream_sender, stream_receiver) = futures_channel::mpsc::unbounded();
+
+        thread::spawn(move || {
+            let send = |(file, file_analysis): (RelativePathBuf, FileAnalysis)| {
+                let dependencies = file_analysis.dependencies.into_keys().collect();
+                stream_sender.unbounded_send((file, dependencies)).is_ok()
+            };
+
+            let sent = if context.config.single_threaded() {
+                walkers
+                    .into_iter()
+                    .flat_map(walk_files)
+                    .take_while(|_| !context.is_cancelled())
+                    .flat_map(|path| context.check_file(&path))
+                    .all(send)
+            } else {
+                let (file_sender, file_receiver) = mpsc::channel();
+                let (dependency_sender, dependency_receiver) = mpsc::channel();
+
+                let handle = spawn_dependency_senders(file_receiver, dependency_sender, &context);
+
+                let walk_context = Arc::clone(&context);
+                let walk_handle = thread::spawn(move || {
+                    let nums_of_thread = num_cpus::get();
+
+                    for mut walker in walkers {
+                        let parallel_walker = walker.threads(nums_of_thread).build_parallel();
+                        spawn_file_senders(parallel_walker, file_sender.clone(), &walk_context);
+                    }
+                });
+
+                // a dropped receiver stops the workers and then the walkers
+                let sent = dependency_receiver.into_iter().all(send);
+
+                handle.join().unwrap();
+                walk_handle.join().unwrap();
+
+                sent
+            };
+
+            if sent && !context.is_cancelled() {
+                context.check_manifest_fields().into_iter().all(send);
+            }
+        });
+
+        Ok(stream_receiver)
+    }
+
+    /// check dependencies and check them again whenever files of the walk change.
+    ///
+    /// The directory is watched by the file system notifications, changes made within one
+    /// debounce delay of another are debounced into one check. Analyses of files are cached
+    /// by modification times, so only changed files are parsed again, while a changed
+    /// manifest, lockfile, tsconfig or baseline checks every file again. Changes of ignored
+    /// files like `node_modules` don't trigger checks. Watching runs until the cancellation
+    /// flag is set.
+    pub fn watch(&self, callback: impl Fn(CheckerResult)) -> Result<(), DepcheckError> {
+        let directory = self.config.get_directory();
+
+        // notifications name absolute paths, they're mapped back to paths of the walk
+        let absolute_directory = env::current_dir()
+            .map(|current_directory| current_directory.join(directory))
+            .map_err(|error| DepcheckError::io(directory, error))?;
+
+        let (event_sender, event_receiver) = mpsc::channel();
+        let watch_error = |source| DepcheckError::Watch {
+            path: directory.to_path_buf(),
+            source,
+        };
+        let mut watcher = notify::recommended_watcher(event_sender).map_err(watch_error)?;
+        watcher
+            .watch(&absolute_directory, RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+
+        let mut context = Arc::new(self.build_context()?);
+        let mut cache = self
+            .analyze_watched_files(&context, self.walk_watched_files()?)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        callback(self.check_cache(&context, &cache)?);
+
+        while let Some(changed_paths) = self.wait_for_changes(&event_receiver, &absolute_directory)
+        {
+            log::debug!("files {:#?} changed", changed_paths);
+
+            let is_context_changed = changed_paths.iter().any(|path| self.is_context_file(path));
+
+            if is_context_changed {
+                context = match self.build_context() {
+                    Ok(context) => Arc::new(context),
+                    // a manifest may be saved partially, it's checked again on the next change
+                    Err(error) => {
+                        log::error!("failed to load changed manifests {:#?}", error);
+                        continue;
+                    }
+                };
+                cache.clear();
+            }
+
+            let changed_files = changed_paths
+                .iter()
+                .filter_map(|path| context.get_file(path))
+                .collect::<HashSet<_>>();
+
+            // analyses of specials including a changed entry file are stale as well
+            let mut stale_paths = cache
+                .iter()
+                .filter(|(path, cached_analysis)| {
+                    let is_modified = changed_paths.contains(*path)
+                        && cached_analysis.modified_time != get_modified_time(path);
+
+                    let file = context.get_file(path);
+                    let is_entry_file_changed =
+                        cached_analysis
+                            .files_analyses
+                            .iter()
+                            .any(|(entry_file, _)| {
+                                Some(entry_file) != file.as_ref()
+                                    && changed_files.contains(entry_file)
+                            });
+
+                    is_modified || is_entry_file_changed
+                })
+                .map(|(path, _)| path.clone())
+                .collect::<BTreeSet<_>>();
+
+            let cached_files = cache.len();
+
+            // only unknown paths like new files or removed directories need another walk
+            if changed_paths.iter().any(|path| !cache.contains_key(path)) {
+                let walked_paths = match self.walk_watched_files() {
+                    Ok(walked_paths) => walked_paths,
+                    Err(error) => {
+                        log::error!("failed to walk changed files {:#?}", error);
+                        continue;
+                    }
+                };
+
+                cache.retain(|path, _| walked_paths.contains(path));
+                stale_paths.extend(
+                    walked_paths
+                        .into_iter()
+                        .filter(|path| !cache.contains_key(path)),
+                );
+            } else {
+                cache.retain(|path, _| !changed_paths.contains(path) || path.is_file());
+            }
+
+            stale_paths.retain(|path| path.is_file());
+
+            if stale_paths.is_empty() && cache.len() == cached_files {
+                continue;
+            }
+
+            cache.extend(self.analyze_watched_files(&context, stale_paths.into_iter().collect()));
+
+            match self.check_cache(&context, &cache) {
+                Ok(result) => callback(result),
+                Err(DepcheckError::Cancelled { .. }) => break,
+                Err(error) => log::error!("failed to check changed files {:#?}", error),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Wait for changes of watched files and collect them until no file changes within the
+    /// debounce delay, `None` is returned once watching is cancelled.
+    fn wait_for_changes(
+        &self,
+        event_receiver: &Receiver<notify::Result<Event>>,
+        absolute_directory: &Path,
+    ) -> Option<BTreeSet<PathBuf>> {
+        let directory = self.config.get_directory();
+        let mut changed_paths = BTreeSet::new();
+
+        loop {
+            // a file changing again and again doesn't block the cancellation
+            if self.is_cancelled() {
+                return None;
+            }
+
+            let timeout = if changed_paths.is_empty() {
+                WATCH_CANCELLATION_INTERVAL
+            } else {
+                WATCH_DEBOUNCE
+            };
+
+            match event_receiver.recv_timeout(timeout) {
+                Ok(Ok(event)) if !event.kind.is_access() => {
+                    changed_paths.extend(
+                        event
+                            .paths
+                            .iter()
+                            .filter_map(|path| path.strip_prefix(absolute_directory).ok())
+                            .filter(|path| !self.is_ignored_change(path))
+                            .map(|path| directory.join(path)),
+                    );
+                }
+                Ok(Ok(_)) => {}
+                Ok(Err(error)) => log::error!("watch error {:#?}", error),
+                Err(RecvTimeoutError::Timeout) if !changed_paths.is_empty() => {
+                    return Some(changed_paths);
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return None,
+            }
+        }
+    }
+
+    /// Check if a changed path relative to the directory is never walked, like files of
+    /// `node_modules` except of included modules, so it doesn't trigger another walk.
+    fn is_ignored_change(&self, path: &Path) -> bool {
+        let is_included_module = self
+            .config
+            .get_include_modules()
+            .iter()
+            .any(|module| path.starts_with(Path::new("node_modules").join(module)));
+
+        path.components().any(|component| {
+            let component = component.as_os_str();
+
+            (component == "node_modules" && !is_included_module)
+                || VCS_DIRECTORIES
+                    .iter()
+                    .any(|vcs_directory| component == *vcs_directory)
+        })
+    }
+
+    /// Check if a path is read to build a context of file checks, like the `package.json`.
+    fn is_context_file(&self, path: &Path) -> bool {
+        let directory = self.config.get_directory();
+
+        let lockfile = match self.config.get_dependency_source() {
+            DependencySource::Lockfile(lockfile) => Some(lockfile),
+            _ => None,
+        };
+
+        [
+            Path::new("package.json"),
+            Path::new("tsconfig.json"),
+            Path::new(BASELINE_FILE),
+        ]
+        .into_iter()
+        .chain(
+            self.config
+                .get_manifest_paths()
+                .iter()
+                .map(PathBuf::as_path),
+        )
+        .chain(lockfile.map(PathBuf::as_path))
+        .any(|context_file| directory.join(context_file) == path)
+    }
+
+    /// Walk files of the directory and of included modules.
+    fn walk_watched_files(&self) -> Result<BTreeSet<PathBuf>, DepcheckError> {
+        let paths = self
+            .build_walkers()?
+            .into_iter()
+            .flat_map(walk_files)
+            .collect();
+
+        Ok(paths)
+    }
+
+    /// Analyze watched files, in parallel unless the check is single threaded.
+    fn analyze_watched_files(
+        &self,
+        context: &CheckContext,
+        paths: Vec<PathBuf>,
+    ) -> Vec<(PathBuf, CachedAnalysis)> {
+        let analyze = |paths: &[PathBuf]| {
+            paths
+                .iter()
+                .take_while(|_| !context.is_cancelled())
+                .map(|path| (path.clone(), CachedAnalysis::new(context, path)))
+                .collect::<Vec<_>>()
+        };
+
+        let nums_of_thread = num_cpus::get();
+
+        if self.config.single_threaded() || paths.len() < nums_of_thread {
+            return analyze(paths.as_slice());
+        }
+
+        let chunk_size = (paths.len() + nums_of_thread - 1) / nums_of_thread;
+
+        crossbeam::scope(|scope| {
+            let handles = paths
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move |_| analyze(chunk)))
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        })
+        .unwrap()
+    }
+
+    /// Check dependencies of cached analyses of watched files.
+    fn check_cache(
+        &self,
+        context: &CheckContext,
+        cache: &HashMap<PathBuf, CachedAnalysis>,
+    ) -> Result<CheckerResult, DepcheckError> {
+        let files_analyses = cache
+            .values()
+            .flat_map(|cached_analysis| cached_analysis.files_analyses.iter().cloned())
+            .collect();
+
+        let directory_analysis = self.aggregate_files(context, files_analyses)?;
+
+        self.build_result(context, directory_analysis)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_deref()
+            .map_or(false, |cancellation| cancellation.load(Ordering::Relaxed))
+    }
+
+    /// Load the package and tsconfig of the directory and build a context of file checks.
+    fn build_context(&self) -> Result<CheckContext, DepcheckError> {
         let directory = self.config.get_directory();
 
         log::debug!("checking directory {:#?}", directory);
 
-        let package = load_module(directory)
-            .wrap_err_with(|| format!("Failed to read package json from {:?}", directory))?;
+        // only the checked manifests are parsed twice to keep the order of dependencies
+        let load_manifest = |path: &Path| {
+            if self.config.preserve_manifest_order() {
+                Package::from_path_with_declaration_order(path)
+            } else {
+                Package::from_path(path)
+            }
+        };
+
+        let mut package = load_manifest(&directory.join("package.json"))?;
+
+        for path in self.config.get_manifest_paths() {
+            let overlay = load_manifest(&directory.join(path))?;
+
+            log::debug!("merging manifest {:#?}", path);
+
+            package = package.merge(overlay);
+        }
 
         log::debug!("loaded package json {:#?}", package);
 
-        let using_dependencies = self.check_directory(Arc::new(package.clone()))?;
+        let package = match self.config.get_dependency_source() {
+            DependencySource::Manifest => package,
+            DependencySource::Lockfile(path) => {
+                let path = directory.join(path);
+                let locked_dependencies = LockedDependencies::from_path(&path)?;
 
-        let result = CheckerResult::new(using_dependencies, package, &self.config);
+                log::debug!("loaded locked dependencies {:#?}", locked_dependencies);
 
-        Ok(result)
+                locked_dependencies.apply(package)
+            }
+            DependencySource::Explicit(dependencies) => Package {
+                dependencies: dependencies.clone(),
+                dev_dependencies: DepsSet::new(),
+                peer_dependencies: DepsSet::new(),
+                optional_dependencies: DepsSet::new(),
+                ..package
+            },
+        };
+
+        let tsconfig = load_tsconfig(directory)
+            .map_err(|error| log::debug!("failed to read tsconfig json {:#?}", error))
+            .unwrap_or_default();
+
+        log::debug!("loaded tsconfig json {:#?}", tsconfig);
+
+        Ok(CheckContext {
+            config: Arc::clone(&self.config),
+            parser: Arc::new(Parser::clone(&self.parser).with_package_type(package.package_type)),
+            base_url: tsconfig.get_base_url(directory),
+            path_aliases: tsconfig.get_path_aliases(directory),
+            package,
+            specials: get_specials(&self.config),
+            dynamic_resolver: self.dynamic_resolver.clone(),
+            specifier_mapper: self.specifier_mapper.clone(),
+            cancellation: self.cancellation.clone(),
+            bin_dependencies: Arc::new(BinDependencies::new(directory)),
+        })
     }
 
     fn check_directory(
         &self,
-        package: Arc<Package>,
-    ) -> eyre::Result<BTreeMap<String, HashSet<String>>> {
+        context: &Arc<CheckContext>,
+    ) -> Result<DirectoryAnalysis, DepcheckError> {
+        let walkers = self.build_walkers()?;
+
+        let files_analyses = if self.config.single_threaded() {
+            check_files_sequentially(walkers, context)
+        } else {
+            check_files_in_parallel(walkers, context)
+        };
+
+        self.aggregate_files(context, files_analyses)
+    }
+
+    /// Aggregate analyses of files and of `package.json` fields into dependencies of the
+    /// directory.
+    fn aggregate_files(
+        &self,
+        context: &CheckContext,
+        mut files_analyses: Vec<(RelativePathBuf, FileAnalysis)>,
+    ) -> Result<DirectoryAnalysis, DepcheckError> {
+        // entry files of specials may be analyzed more than once
+        let files_scanned = files_analyses
+            .iter()
+            .filter(|(_, file_analysis)| !file_analysis.parse_failed)
+            .map(|(file, _)| file)
+            .collect::<HashSet<_>>()
+            .len();
+
+        files_analyses.extend(context.check_manifest_fields());
+
+        if context.is_cancelled() {
+            return Err(DepcheckError::Cancelled {
+                directory: self.config.get_directory().to_path_buf(),
+            });
+        }
+
+        let absolute_directory = match self.config.get_path_base() {
+            PathBase::Directory => None,
+            PathBase::Absolute => {
+                let directory = self.config.get_directory();
+                let absolute_directory = directory
+                    .canonicalize()
+                    .map_err(|error| DepcheckError::io(directory, error))?;
+                Some(absolute_directory)
+            }
+        };
+
+        // a file may be analyzed twice when it's an entry file of a special as well
+        let mut file_import_counts = BTreeMap::new();
+        let mut dynamic_usage = Vec::new();
+        let mut parse_errors = Vec::new();
+        let mut imported_dependencies = HashSet::new();
+
+        for (file, file_analysis) in files_analyses {
+            let file = match &absolute_directory {
+                Some(absolute_directory) => file.to_path(absolute_directory).display().to_string(),
+                None => file.to_string(),
+            };
+
+            if file_analysis.parse_failed {
+                parse_errors.push(file.clone());
+            }
+
+            imported_dependencies.extend(file_analysis.imported);
+
+            for (dependency, count) in file_analysis.dependencies {
+                let file_import_count = file_import_counts
+                    .entry((dependency, file.clone()))
+                    .or_insert(0);
+                *file_import_count = count.max(*file_import_count);
+            }
+
+            dynamic_usage.extend(file_analysis.dynamic_usage.into_iter().map(|source| {
+                DynamicUsage {
+                    file: file.clone(),
+                    source,
+                }
+            }));
+        }
+
+        let mut using_dependencies = BTreeMap::new();
+        let mut import_counts = BTreeMap::new();
+
+        for ((dependency, file), count) in file_import_counts {
+            *import_counts.entry(dependency.clone()).or_insert(0) += count;
+
+            let files = using_dependencies
+                .entry(dependency)
+                .or_insert_with(|| HashSet::with_capacity(100));
+            files.insert(file);
+        }
+
+        dynamic_usage.sort();
+        dynamic_usage.dedup();
+        parse_errors.sort();
+        parse_errors.dedup();
+
+        let reexported_only = using_dependencies
+            .keys()
+            .filter(|dependency| !imported_dependencies.contains(*dependency))
+            .cloned()
+            .collect();
+
+        Ok(DirectoryAnalysis {
+            using_dependencies,
+            import_counts,
+            dynamic_usage,
+            files_scanned,
+            parse_errors,
+            reexported_only,
+        })
+    }
+
+    /// run a single special as if it's the only enabled special and get dependencies it detects
+    /// of each matched config file, imports of files aren't analyzed.
+    pub fn run_special(&self, name: &str) -> Result<SpecialReport, DepcheckError> {
+        let config = Config::clone(&self.config).with_specials(vec![String::from(name)]);
+        let checker = Checker {
+            config: Arc::new(config),
+            ..self.clone()
+        };
+        let context = checker.build_context()?;
+
+        // plugin arrays are enabled by their globs rather than by the name
+        let special = context
+            .specials
+            .iter()
+            .find(|special| special.name() == name)
+            .ok_or_else(|| DepcheckError::UnknownSpecial {
+                name: String::from(name),
+            })?;
+
+        let special_context = SpecialContext {
+            parser: &context.parser,
+            package: &context.package,
+            config: &context.config,
+            directory: context.config.get_directory(),
+            bin_dependencies: &context.bin_dependencies,
+        };
+
+        let mut report = SpecialReport {
+            special: String::from(name),
+            ..Default::default()
+        };
+
+        for path in checker
+            .build_walkers()?
+            .into_iter()
+            .flat_map(walk_files)
+            .filter(|path| special.is_match(path))
+        {
+            let file = match context.get_file(&path) {
+                Some(file) => file.to_string(),
+                None => continue,
+            };
+
+            for dependency in special.detect(&path, &special_context) {
+                report
+                    .dependencies
+                    .entry(dependency)
+                    .or_default()
+                    .insert(file.clone());
+            }
+
+            report.entry_files.extend(
+                special
+                    .entry_files(&path, &special_context)
+                    .iter()
+                    .filter_map(|entry_file| context.get_file(entry_file))
+                    .map(|entry_file| entry_file.to_string()),
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// check imported package specifiers whose bindings are never referenced in a file.
+    pub fn check_dead_imports(
+        &self,
+    ) -> Result<BTreeMap<RelativePathBuf, Vec<String>>, DepcheckError> {
+        let context = self.build_context()?;
+
+        let mut dead_imports = BTreeMap::new();
+
+        for path in self.build_walkers()?.into_iter().flat_map(walk_files) {
+            let file_dead_imports = context
+                .parser
+                .parse_file(&path)
+                .map(|(module, _)| collect_dead_imports(&module));
+
+            if let (Some(file), Some(file_dead_imports)) =
+                (context.get_file(&path), file_dead_imports)
+            {
+                if !file_dead_imports.is_empty() {
+                    dead_imports.insert(file, file_dead_imports);
+                }
+            }
+        }
+
+        Ok(dead_imports)
+    }
+
+    /// collect package names of external specifiers imported by each file, core modules and
+    /// undeclared packages are kept and peer dependencies aren't resolved.
+    pub fn collect_specifiers(
+        &self,
+    ) -> Result<BTreeMap<RelativePathBuf, BTreeSet<String>>, DepcheckError> {
+        let context = self.build_context()?;
+
+        let mut specifiers = BTreeMap::new();
+
+        for path in self.build_walkers()?.into_iter().flat_map(walk_files) {
+            let file_specifiers = context.parser.parse_file(&path).map(|(module, _)| {
+                let comments = SingleThreadedComments::default();
+
+                analyze_dependencies(&module, &comments)
+                    .into_iter()
+                    .map(Dependency::new)
+                    .filter(Dependency::is_external)
+                    .filter_map(|dependency| dependency.get_dependency())
+                    .collect::<BTreeSet<_>>()
+            });
+
+            if let (Some(file), Some(file_specifiers)) = (context.get_file(&path), file_specifiers)
+            {
+                if !file_specifiers.is_empty() {
+                    specifiers.insert(file, file_specifiers);
+                }
+            }
+        }
+
+        Ok(specifiers)
+    }
+
+    /// Build walkers of the directory and of included modules.
+    fn build_walkers(&self) -> Result<Vec<WalkBuilder>, DepcheckError> {
         let directory = self.config.get_directory();
+        let mut walkers = vec![self.build_walker(directory)?];
+
+        for module in self.config.get_include_modules() {
+            let module_directory = directory.join("node_modules").join(module);
+
+            if !module_directory.is_dir() {
+                log::warn!("included module {} is not installed", module);
+                continue;
+            }
+
+            walkers.push(self.build_walker(&module_directory)?);
+        }
+
+        Ok(walkers)
+    }
+
+    fn build_walker(&self, directory: &Path) -> Result<WalkBuilder, DepcheckError> {
         let mut override_builder = OverrideBuilder::new(directory);
 
         for pattern in self.config.get_ignore_patterns() {
             override_builder
                 .add(&format!("!{pattern}"))
-                .wrap_err_with(|| format!("Malformed ignore pattern: {pattern}"))?;
+                .map_err(|source| DepcheckError::Pattern {
+                    pattern: pattern.to_owned(),
+                    source,
+                })?;
         }
 
         let overrides = override_builder
             .build()
-            .wrap_err_with(|| "Failed to build override builder")?;
+            .map_err(|source| DepcheckError::Walk {
+                path: directory.to_path_buf(),
+                source,
+            })?;
         let mut walker = WalkBuilder::new(directory);
 
         walker.overrides(overrides);
+        walker.hidden(!self.config.scan_hidden());
+
+        // installed packages like included modules or a package checked as it's shipped are
+        // ignored by `node_modules` rules of parent directories, only their own rules apply
+        if is_installed_package(directory) {
+            walker.parents(false);
+        }
 
         if let Some(path) = self.config.ignore_path() {
             walker.add_custom_ignore_filename(path);
         }
 
-        let (file_sender, file_receiver) = mpsc::channel();
-        let (dependency_sender, dependency_receiver) = mpsc::channel();
+        let root = if self.config.follow_symlinks() {
+            walker.follow_links(true);
 
-        let nums_of_thread = num_cpus::get();
-        let parallel_walker = walker.threads(nums_of_thread).build_parallel();
+            let root = directory
+                .canonicalize()
+                .map_err(|error| DepcheckError::io(directory, error))?;
+            Some(root)
+        } else {
+            None
+        };
 
-        let mut using_dependencies = BTreeMap::new();
+        let scan_extensions = self.config.get_scan_extensions().cloned();
+        let specials = get_specials(&self.config);
 
-        let handle = spawn_dependency_senders(
-            file_receiver,
-            dependency_sender,
-            &self.config,
-            &self.parser,
-            &package,
-        );
+        // included modules under `node_modules` are never tracked
+        let tracked_files =
+            if self.config.git_tracked_only() && directory == self.config.get_directory() {
+                load_git_tracked_files(directory)
+            } else {
+                None
+            };
 
-        spawn_file_senders(parallel_walker, file_sender);
+        walker.filter_entry(move |entry| {
+            if entry.depth() == 0 {
+                return true;
+            }
 
-        handle.join().unwrap();
+            let is_file = entry
+                .file_type()
+                .map_or(false, |file_type| file_type.is_file());
+            let is_scanned = match &scan_extensions {
+                Some(scan_extensions) if is_file => {
+                    is_scanned_file(entry.path(), scan_extensions, &specials)
+                }
+                _ => true,
+            };
 
-        while let Ok((file, file_dependencies)) = dependency_receiver.recv() {
-            for dependency in file_dependencies {
-                let files = using_dependencies
-                    .entry(dependency)
-                    .or_insert_with(|| HashSet::with_capacity(100));
-                files.insert(file.to_string());
-            }
-        }
+            let is_tracked = match &tracked_files {
+                Some(tracked_files) if is_file => tracked_files.contains(entry.path()),
+                _ => true,
+            };
+
+            let is_contained = root
+                .as_deref()
+                .map_or(true, |root| is_contained(entry.path(), root));
+
+            let is_vcs_directory = entry
+                .file_name()
+                .to_str()
+                .map_or(false, |file_name| VCS_DIRECTORIES.contains(&file_name));
+
+            is_scanned
+                && is_tracked
+                && is_contained
+                && !is_vcs_directory
+                && !is_module(entry.path())
+        });
+
+        Ok(walker)
+    }
+}
+
+fn check_files_sequentially(
+    walkers: Vec<WalkBuilder>,
+    context: &CheckContext,
+) -> Vec<(RelativePathBuf, FileAnalysis)> {
+    walkers
+        .into_iter()
+        .flat_map(walk_files)
+        .take_while(|_| !context.is_cancelled())
+        .flat_map(|path| context.check_file(&path))
+        .collect()
+}
+
+fn check_files_in_parallel(
+    walkers: Vec<WalkBuilder>,
+    context: &Arc<CheckContext>,
+) -> Vec<(RelativePathBuf, FileAnalysis)> {
+    let (file_sender, file_receiver) = mpsc::channel();
+    let (dependency_sender, dependency_receiver) = mpsc::channel();
+
+    let nums_of_thread = num_cpus::get();
+
+    let handle = spawn_dependency_senders(file_receiver, dependency_sender, context);
+
+    for mut walker in walkers {
+        let parallel_walker = walker.threads(nums_of_thread).build_parallel();
+        spawn_file_senders(parallel_walker, file_sender.clone(), context);
+    }
+
+    drop(file_sender);
 
-        Ok(using_dependencies)
+    handle.join().unwrap();
+
+    dependency_receiver.into_iter().collect()
+}
+
+/// Check if a path resolves inside of the root, so followed symlinks don't escape it.
+fn is_contained(path: &Path, root: &Path) -> bool {
+    match path.canonicalize() {
+        Ok(path) => path.starts_with(root),
+        Err(error) => {
+            log::warn!("failed to resolve {:#?}: {:#?}", path, error);
+            false
+        }
     }
 }
 
-fn spawn_file_senders(parallel_walker: ignore::WalkParallel, file_sender: Sender<WorkerResult>) {
+/// Check if a directory is inside of `node_modules`.
+fn is_installed_package(directory: &Path) -> bool {
+    directory
+        .components()
+        .any(|component| component.as_os_str() == "node_modules")
+}
+
+/// Check if a file has one of scan extensions or is matched by a special.
+fn is_scanned_file(
+    path: &Path,
+    scan_extensions: &HashSet<String>,
+    specials: &[Box<dyn Special>],
+) -> bool {
+    let has_scan_extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map_or(false, |extension| scan_extensions.contains(extension));
+
+    has_scan_extension || specials.iter().any(|special| special.is_match(path))
+}
+
+/// Walk files in the current thread.
+fn walk_files(walker: WalkBuilder) -> impl Iterator<Item = PathBuf> {
+    walker.build().filter_map(|entry| match entry {
+        Ok(entry) => match entry.file_type() {
+            Some(file_type) if file_type.is_file() => Some(entry.into_path()),
+            _ => None,
+        },
+        Err(error) => {
+            log::error!("walk error {:#?}", error);
+            None
+        }
+    })
+}
+
+fn spawn_file_senders(
+    parallel_walker: ignore::WalkParallel,
+    file_sender: Sender<WorkerResult>,
+    context: &Arc<CheckContext>,
+) {
     parallel_walker.run(|| {
         let file_sender = file_sender.clone();
+        let context = Arc::clone(context);
         Box::new(move |entry| {
             log::debug!("walk entry {:#?}", entry);
+
+            if context.is_cancelled() {
+                return ignore::WalkState::Quit;
+            }
+
             return match entry {
                 Ok(ref entry) => {
                     if entry.depth() == 0 {
@@ -161,14 +1174,10 @@ fn spawn_file_senders(parallel_walker: ignore::WalkParallel, file_sender: Sender
 
 fn spawn_dependency_senders(
     file_receiver: Receiver<WorkerResult>,
-    dependency_sender: Sender<(RelativePathBuf, HashSet<String>)>,
-    config: &Arc<Config>,
-    parser: &Arc<Parser>,
-    package: &Arc<Package>,
+    dependency_sender: Sender<(RelativePathBuf, FileAnalysis)>,
+    context: &Arc<CheckContext>,
 ) -> thread::JoinHandle<Vec<()>> {
-    let config = Arc::clone(config);
-    let parser = Arc::clone(parser);
-    let package = Arc::clone(package);
+    let context = Arc::clone(context);
     thread::spawn(move || {
         let shared_file_receiver = Arc::new(Mutex::new(file_receiver));
 
@@ -177,9 +1186,7 @@ fn spawn_dependency_senders(
 
         for _ in 0..nums_of_thread {
             let file_receiver = Arc::clone(&shared_file_receiver);
-            let config = Arc::clone(&config);
-            let parser = Arc::clone(&parser);
-            let package = Arc::clone(&package);
+            let context = Arc::clone(&context);
             let dependency_sender = dependency_sender.clone();
 
             let handle = thread::spawn(move || loop {
@@ -194,26 +1201,18 @@ fn spawn_dependency_senders(
                 };
 
                 drop(lock);
-                let comments = SingleThreadedComments::default();
 
-                let file = path
-                    .strip_prefix(config.get_directory())
-                    .map(|path| RelativePathBuf::from_path(path).ok())
-                    .ok()
-                    .flatten();
-                let file_dependencies = parser.parse_file(&path).map(|(module, syntax)| {
-                    analyze_dependencies(&module, &comments)
-                        .into_iter()
-                        .map(Dependency::new)
-                        .filter(|dependency| dependency.is_external())
-                        .flat_map(|dependency| {
-                            dependency.extract_dependencies(&syntax, &package, &config)
-                        })
-                        .collect::<HashSet<_>>()
-                });
+                if context.is_cancelled() {
+                    break;
+                }
+
+                let sent = context
+                    .check_file(&path)
+                    .into_iter()
+                    .all(|file_analysis| dependency_sender.send(file_analysis).is_ok());
 
-                if let (Some(file), Some(file_dependencies)) = (file, file_dependencies) {
-                    dependency_sender.send((file, file_dependencies)).unwrap();
+                if !sent {
+                    break;
                 }
             });
 
@@ -226,3 +1225,373 @@ fn spawn_dependency_senders(
             .collect::<Vec<_>>()
     })
 }
+
+/// Dependencies and dynamic usage found in a directory.
+#[derive(Debug, Default)]
+struct DirectoryAnalysis {
+    /// Key is dependency and value is unique file paths.
+    using_dependencies: BTreeMap<String, HashSet<String>>,
+    /// Key is dependency and value is number of its imports in all files.
+    import_counts: BTreeMap<String, usize>,
+    dynamic_usage: Vec<DynamicUsage>,
+    /// Number of unique analyzed files, except of `package.json`.
+    files_scanned: usize,
+    /// Sorted files which failed to parse.
+    parse_errors: Vec<String>,
+    /// Dependencies used only by `export ... from` statements.
+    reexported_only: BTreeSet<String>,
+}
+
+/// Dependencies and dynamic usage found in a file.
+#[derive(Clone, Debug, Default)]
+struct FileAnalysis {
+    /// Key is dependency and value is number of its imports in the file.
+    dependencies: HashMap<String, usize>,
+    /// Dependencies used other than by `export ... from` statements.
+    imported: HashSet<String>,
+    dynamic_usage: Vec<String>,
+    /// The file or one of its embedded scripts failed to parse.
+    parse_failed: bool,
+}
+
+impl FileAnalysis {
+    /// Add dependencies used other than by re-exports, like by specials.
+    fn add_dependencies(&mut self, dependencies: impl IntoIterator<Item = (String, usize)>) {
+        for (dependency, count) in dependencies {
+            self.imported.insert(dependency.clone());
+            *self.dependencies.entry(dependency).or_insert(0) += count;
+        }
+    }
+
+    /// Merge an analysis of a part of the file like an embedded script.
+    fn extend(&mut self, file_analysis: FileAnalysis) {
+        for (dependency, count) in file_analysis.dependencies {
+            *self.dependencies.entry(dependency).or_insert(0) += count;
+        }
+
+        self.imported.extend(file_analysis.imported);
+        self.dynamic_usage.extend(file_analysis.dynamic_usage);
+        self.parse_failed |= file_analysis.parse_failed;
+    }
+}
+
+/// Analyses of a watched file and of entry files of its specials keyed by the modification
+/// time of the file.
+struct CachedAnalysis {
+    modified_time: Option<SystemTime>,
+    files_analyses: Vec<(RelativePathBuf, FileAnalysis)>,
+}
+
+impl CachedAnalysis {
+    fn new(context: &CheckContext, path: &Path) -> Self {
+        CachedAnalysis {
+            modified_time: get_modified_time(path),
+            files_analyses: context.check_file(path),
+        }
+    }
+}
+
+/// Get a modification time of a file, `None` when it's removed.
+fn get_modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Shared state of a directory check.
+struct CheckContext {
+    config: Arc<Config>,
+    parser: Arc<Parser>,
+    package: Package,
+    base_url: Option<PathBuf>,
+    path_aliases: Vec<(String, Vec<PathBuf>)>,
+    specials: Vec<Box<dyn Special>>,
+    dynamic_resolver: Option<Arc<DynamicResolver>>,
+    specifier_mapper: Option<Arc<SpecifierMapper>>,
+    cancellation: Option<Arc<AtomicBool>>,
+    bin_dependencies: Arc<BinDependencies>,
+}
+
+impl CheckContext {
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_deref()
+            .map_or(false, |cancellation| cancellation.load(Ordering::Relaxed))
+    }
+
+    /// Analyze a file and entry files referenced by the file specials.
+    fn check_file(&self, path: &Path) -> Vec<(RelativePathBuf, FileAnalysis)> {
+        let special_context = SpecialContext {
+            parser: &self.parser,
+            package: &self.package,
+            config: &self.config,
+            directory: self.config.get_directory(),
+            bin_dependencies: &self.bin_dependencies,
+        };
+
+        let mut file_analysis = self.analyze_file(path);
+        let mut entry_files = Vec::new();
+
+        for special in self
+            .specials
+            .iter()
+            .filter(|special| special.is_match(path))
+        {
+            let special_dependencies = special.detect(path, &special_context);
+
+            log::debug!(
+                "special {} detected {:#?} in {:#?}",
+                special.name(),
+                special_dependencies,
+                path
+            );
+
+            if !special_dependencies.is_empty() {
+                file_analysis
+                    .get_or_insert_with(FileAnalysis::default)
+                    .add_dependencies(
+                        special_dependencies
+                            .into_iter()
+                            .map(|dependency| (dependency, 1)),
+                    );
+            }
+
+            entry_files.extend(special.entry_files(path, &special_context));
+        }
+
+        iter::once((path.to_path_buf(), file_analysis))
+            .chain(entry_files.into_iter().map(|entry_file| {
+                let entry_file_analysis = self.analyze_file(&entry_file);
+                (entry_file, entry_file_analysis)
+            }))
+            .filter_map(
+                |(path, file_analysis)| match (self.get_file(&path), file_analysis) {
+                    (Some(file), Some(file_analysis)) => Some((file, file_analysis)),
+                    _ => None,
+                },
+            )
+            .collect()
+    }
+
+    /// Analyze packages referenced by configured fields of the `package.json`.
+    fn check_manifest_fields(&self) -> Option<(RelativePathBuf, FileAnalysis)> {
+        let fields = self.config.get_manifest_package_fields();
+
+        if fields.is_empty() {
+            return None;
+        }
+
+        let path = self.config.get_directory().join("package.json");
+        let content = fs::read(&path)
+            .map_err(|error| log::error!("failed to read {:#?}: {:#?}", path, error))
+            .ok()?;
+        let manifest = serde_json::from_slice::<serde_json::Value>(&content)
+            .map_err(|error| log::error!("failed to parse {:#?}: {:#?}", path, error))
+            .ok()?;
+
+        let dependencies = collect_manifest_field_packages(&manifest, fields)
+            .into_iter()
+            .filter(|dependency| self.package.is_any_dependency(dependency))
+            .map(|dependency| (dependency, 1));
+
+        let mut file_analysis = FileAnalysis::default();
+        file_analysis.add_dependencies(dependencies);
+
+        Some((RelativePathBuf::from("package.json"), file_analysis))
+    }
+
+    fn analyze_file(&self, path: &Path) -> Option<FileAnalysis> {
+        if is_css_file(path) {
+            return self.analyze_css_file(path);
+        }
+
+        if is_embedded_script_file(path, self.config.get_embedded_script_extensions()) {
+            return self.analyze_embedded_scripts(path);
+        }
+
+        match self.parser.parse_file_with_source(path) {
+            Some((module, syntax, source, comments)) => {
+                Some(self.analyze_module(path, &module, &syntax, &source, &comments))
+            }
+            // files of other extensions aren't parsed at all
+            None if self.parser.syntax_for_path(path).is_some() => Some(FileAnalysis {
+                parse_failed: true,
+                ..Default::default()
+            }),
+            None => None,
+        }
+    }
+
+    fn analyze_module(
+        &self,
+        path: &Path,
+        module: &Module,
+        syntax: &Syntax,
+        source: &SourceFile,
+        comments: &SingleThreadedComments,
+    ) -> FileAnalysis {
+        // packages declared by `depcheck-use` comments are filtered like imports
+        let declared_dependencies = collect_use_directives(comments)
+            .into_iter()
+            .map(|specifier| Dependency::from_specifier(specifier, DependencyKind::Import));
+
+        // functions created by `createRequire` of ES modules are called like `require`
+        let created_requires = collect_created_requires(module)
+            .into_iter()
+            .map(|specifier| Dependency::from_specifier(specifier, DependencyKind::Require));
+
+        // loader hooks registered at runtime are imported by Node.js
+        let registered_loaders = collect_registered_loaders(module)
+            .into_iter()
+            .map(|specifier| Dependency::from_specifier(specifier, DependencyKind::Import));
+
+        // modules of SystemJS and AMD loaders are loaded like dynamic imports
+        let legacy_dependencies = collect_legacy_module_specifiers(module)
+            .into_iter()
+            .map(|specifier| Dependency::from_specifier(specifier, DependencyKind::Import));
+
+        // augmented packages are referenced only by types, like by `import("pkg").Type`, source
+        // files may declare modules of their own which aren't packages
+        let augmented_dependencies = is_declaration_file(path)
+            .then(|| collect_augmented_modules(module))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|specifier| Dependency::from_specifier(specifier, DependencyKind::ImportType));
+
+        // only leading literals of concatenated specifiers are known
+        let concatenated_dependencies = collect_concatenated_specifiers(module)
+            .into_iter()
+            .map(|specifier| Dependency::from_specifier(specifier, DependencyKind::Require));
+
+        let dependencies = analyze_dependencies(module, comments)
+            .into_iter()
+            .map(Dependency::new)
+            .chain(self.resolve_dynamic_dependencies(module, source))
+            .chain(concatenated_dependencies)
+            .chain(created_requires)
+            .chain(legacy_dependencies)
+            .chain(registered_loaders)
+            .chain(augmented_dependencies)
+            .chain(declared_dependencies);
+
+        FileAnalysis {
+            dynamic_usage: collect_dynamic_usage(module, source),
+            ..self.extract_dependencies(dependencies, syntax)
+        }
+    }
+
+    /// Analyze inline `<script>` elements of a template as JavaScript.
+    fn analyze_embedded_scripts(&self, path: &Path) -> Option<FileAnalysis> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| log::error!("failed to read {:#?}: {:#?}", path, error))
+            .ok()?;
+
+        let mut file_analysis = FileAnalysis::default();
+
+        for script in collect_embedded_scripts(&content) {
+            let parsed =
+                self.parser
+                    .parse_embedded_source(path, script.source, script.is_es_module);
+
+            match parsed {
+                Some((module, syntax, source, comments)) => {
+                    let script_analysis =
+                        self.analyze_module(path, &module, &syntax, &source, &comments);
+                    file_analysis.extend(script_analysis);
+                }
+                None => file_analysis.parse_failed = true,
+            }
+        }
+
+        Some(file_analysis)
+    }
+
+    fn analyze_css_file(&self, path: &Path) -> Option<FileAnalysis> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| log::error!("failed to read {:#?}: {:#?}", path, error))
+            .ok()?;
+
+        let specifiers = match path.parent() {
+            Some(directory) if is_less_file(path) => collect_less_specifiers(&content, directory),
+            _ => collect_css_specifiers(&content),
+        };
+
+        let dependencies = specifiers
+            .into_iter()
+            .map(|specifier| Dependency::from_specifier(specifier, DependencyKind::Import));
+
+        // stylesheets have no type packages, so they are handled like JavaScript files
+        Some(self.extract_dependencies(dependencies, &Syntax::Es(Default::default())))
+    }
+
+    /// Get package names of external dependencies which aren't resolved against `baseUrl`
+    /// mapped to numbers of their imports, and which of them aren't only re-exported.
+    fn extract_dependencies(
+        &self,
+        dependencies: impl Iterator<Item = Dependency>,
+        syntax: &Syntax,
+    ) -> FileAnalysis {
+        let mut file_analysis = FileAnalysis::default();
+
+        let dependencies = dependencies
+            .flat_map(|dependency| dependency.resolve_subpath_imports(&self.package))
+            .flat_map(|dependency| dependency.resolve_path_aliases(&self.path_aliases))
+            .filter(|dependency| dependency.is_external())
+            .filter(|dependency| match self.base_url.as_deref() {
+                Some(base_url) => !dependency.is_base_url_module(base_url),
+                None => true,
+            })
+            .filter_map(|dependency| match self.specifier_mapper.as_deref() {
+                Some(specifier_mapper) => dependency.map_package(specifier_mapper),
+                None => Some(dependency),
+            })
+            .flat_map(|dependency| {
+                let is_reexport = dependency.is_reexport();
+
+                dependency
+                    .extract_dependencies(
+                        syntax,
+                        &self.package,
+                        &self.config,
+                        &self.bin_dependencies,
+                    )
+                    .into_iter()
+                    .map(move |package| (package, is_reexport))
+            });
+
+        for (dependency, is_reexport) in dependencies {
+            if !is_reexport {
+                file_analysis.imported.insert(dependency.clone());
+            }
+
+            *file_analysis.dependencies.entry(dependency).or_insert(0) += 1;
+        }
+
+        file_analysis
+    }
+
+    /// Get dependencies of dynamic imports resolved by the user callback.
+    fn resolve_dynamic_dependencies(
+        &self,
+        module: &Module,
+        source: &SourceFile,
+    ) -> Vec<Dependency> {
+        let dynamic_resolver = match &self.dynamic_resolver {
+            Some(dynamic_resolver) => dynamic_resolver,
+            None => return Vec::new(),
+        };
+
+        collect_dynamic_specifiers(module, source)
+            .iter()
+            .flat_map(|specifier| dynamic_resolver(specifier.as_str()))
+            .map(|specifier| Dependency::from_specifier(specifier, DependencyKind::Import))
+            .collect()
+    }
+
+    fn get_file(&self, path: &Path) -> Option<RelativePathBuf> {
+        path.strip_prefix(self.config.get_directory())
+            .map(|path| RelativePathBuf::from_path(path).ok())
+            .ok()
+            .flatten()
+    }
+}
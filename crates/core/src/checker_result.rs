@@ -1,8 +1,28 @@
+use crate::baseline::{Baseline, BaselineEntry};
 use crate::config::Config;
 use crate::package::{DepsSet, Package};
-use crate::util::is_bin_dependency::is_bin_dependency;
+use crate::util::collect_installs::collect_installs;
+use crate::util::common_devtools::is_common_devtool;
+use crate::util::extract_typed_package::extract_typed_package;
+use crate::util::is_bin_dependency::BinDependencies;
+use crate::util::is_loose_version::is_loose_version;
+use crate::util::is_registry_version::is_registry_version;
+use crate::util::is_unresolved_internal::is_unresolved_internal;
+use crate::util::load_hoisted_dependencies::load_hoisted_dependencies;
+use crate::util::load_module::load_module;
+use globset::{Glob, GlobSetBuilder};
+use ignore::WalkBuilder;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashSet};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::iter;
+use std::path::{Path, PathBuf};
+
+/// Extensions tried by Node.js while resolving a `main` entry without an extension.
+const MAIN_EXTENSIONS: [&str; 3] = ["js", "json", "node"];
+
+/// Extensions of declaration files targeted by `types` conditions.
+const DECLARATION_EXTENSIONS: [&str; 3] = [".d.ts", ".d.mts", ".d.cts"];
 
 /// Dependencies checker result.
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
@@ -16,6 +36,164 @@ pub struct CheckerResult {
     pub unused_dependencies: HashSet<String>,
     /// Unique unused dev dependencies.
     pub unused_dev_dependencies: HashSet<String>,
+    /// Number of imports of dependencies in all files.
+    #[serde(default)]
+    import_counts: BTreeMap<String, usize>,
+    /// Code evaluated at runtime which may hide requires, sorted by files.
+    #[serde(default)]
+    dynamic_usage: Vec<DynamicUsage>,
+    /// Number of files whose dependencies were analyzed.
+    #[serde(default)]
+    files_scanned: usize,
+    /// Files which failed to parse, so their dependencies are unknown.
+    #[serde(default)]
+    parse_errors: Vec<String>,
+    /// Dependencies used only by `export ... from` statements.
+    #[serde(default)]
+    reexported_only: BTreeSet<String>,
+    /// Problems of the config which didn't fail the check, like hoisted scopes matching no
+    /// directories or referenced projects which aren't checked.
+    #[serde(default)]
+    warnings: Vec<String>,
+    /// Baseline entries whose `until` date has passed, their findings are not suppressed.
+    #[serde(default)]
+    expired_baseline: Baseline,
+    /// Package json of the checked directory.
+    #[serde(skip)]
+    package: Package,
+    /// The checked directory.
+    #[serde(skip)]
+    directory: PathBuf,
+    /// Maximum number of files listed for each dependency of reports.
+    #[serde(skip)]
+    max_files_per_dependency: Option<usize>,
+    /// Whether sorted getters keep the declaration order of `package.json`.
+    #[serde(skip)]
+    preserve_manifest_order: bool,
+}
+
+/// Code evaluated at runtime like `eval("require('pkg')")`, so its requires can't be detected.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicUsage {
+    /// The file path.
+    pub file: String,
+    /// The source text of the evaluating call.
+    pub source: String,
+}
+
+/// How much a declared dependency is used.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyWeight {
+    /// Number of files using the dependency.
+    pub files: usize,
+    /// Number of imports of the dependency in all files.
+    pub imports: usize,
+}
+
+/// Dependencies checker report with deterministic ordering.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    /// Missing dependencies. Key is dependency and value is sorted file paths, a truncated
+    /// list ends with a `...N more` entry.
+    pub missing: BTreeMap<String, Vec<String>>,
+    /// Unused dependencies.
+    pub unused: BTreeSet<String>,
+    /// Unused dev dependencies.
+    pub unused_dev: BTreeSet<String>,
+    /// Using dependencies. Key is dependency and value is sorted file paths, a truncated
+    /// list ends with a `...N more` entry.
+    pub using: BTreeMap<String, Vec<String>>,
+    /// Numbers of findings and of scanned files.
+    pub summary: Summary,
+}
+
+/// Headline numbers of a checker result, like for tracking trends in CI.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Summary {
+    /// Number of missing dependencies.
+    pub missing: usize,
+    /// Number of unused dependencies.
+    pub unused: usize,
+    /// Number of unused dev dependencies.
+    pub unused_dev: usize,
+    /// Number of files whose dependencies were analyzed.
+    pub files_scanned: usize,
+}
+
+/// Dependencies detected by a single special, like for debugging its false positives.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecialReport {
+    /// The special name.
+    pub special: String,
+    /// Detected dependencies. Key is dependency and value is sorted config file paths.
+    pub dependencies: BTreeMap<String, BTreeSet<String>>,
+    /// Sorted source files referenced by matched config files, they're checked like imports.
+    pub entry_files: BTreeSet<String>,
+}
+
+/// Why a declared `@types/*` package may be dead weight.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OrphanTypeReason {
+    /// The typed package isn't declared in any section, so the types can be removed alone.
+    BaseAbsent,
+    /// The typed package is declared but unused, so both packages may be removed.
+    BaseUnused,
+}
+
+/// A target of the `package.json` `exports` map which won't resolve once published.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportsIssue {
+    /// The field of the target like `exports["./feature"].import`.
+    pub field: String,
+    /// The target as written in the manifest.
+    pub target: String,
+    /// What's wrong with the target.
+    pub kind: ExportsIssueKind,
+}
+
+/// Kinds of problems of `exports` targets.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportsIssueKind {
+    /// The target isn't a relative path starting with `./`, which Node.js rejects.
+    NonRelativeTarget,
+    /// The target file doesn't exist, or no file matches a `*` pattern.
+    MissingTarget,
+    /// A `types` condition targets a file which isn't a declaration file like `.d.ts`.
+    NonDeclarationTypes,
+}
+
+/// Dependencies usage grouped by `package.json` sections.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionReport {
+    /// Usage of `dependencies`.
+    pub dependencies: SectionUsage,
+    /// Usage of `devDependencies`.
+    pub dev_dependencies: SectionUsage,
+    /// Usage of `peerDependencies`.
+    pub peer_dependencies: SectionUsage,
+    /// Usage of `optionalDependencies`.
+    pub optional_dependencies: SectionUsage,
+}
+
+/// Usage of a single `package.json` section.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionUsage {
+    /// Used dependencies. Key is dependency and value is number of files using it.
+    pub used: BTreeMap<String, usize>,
+    /// Unused dependencies.
+    pub unused: BTreeSet<String>,
 }
 
 impl CheckerResult {
@@ -23,6 +201,22 @@ impl CheckerResult {
         using_dependencies: BTreeMap<String, HashSet<String>>,
         package: Package,
         config: &Config,
+    ) -> CheckerResult {
+        let bin_dependencies = BinDependencies::new(config.get_directory());
+        CheckerResult::new_with_bin_dependencies(
+            using_dependencies,
+            package,
+            config,
+            &bin_dependencies,
+        )
+    }
+
+    /// Create a result reusing bin lookups of the check.
+    pub(crate) fn new_with_bin_dependencies(
+        using_dependencies: BTreeMap<String, HashSet<String>>,
+        package: Package,
+        config: &Config,
+        bin_dependencies: &BinDependencies,
     ) -> CheckerResult {
         let ignore_matches = config
             .get_ignore_matches()
@@ -36,8 +230,14 @@ impl CheckerResult {
                 .filter(|(dependency, _)| !ignore_matches.is_match(dependency.as_str()))
                 .filter(|(dependency, _)| !package.is_any_dependency(dependency))
                 .filter(|(dependency, _)| {
-                    !config.ignore_bin_package()
-                        || !is_bin_dependency(config.get_directory(), dependency)
+                    !is_unresolved_internal(
+                        config.get_directory(),
+                        config.get_unresolved_internal_scopes(),
+                        dependency,
+                    )
+                })
+                .filter(|(dependency, _)| {
+                    !config.ignore_bin_package() || !bin_dependencies.contains(dependency)
                 })
                 .map(|(dependency, files)| {
                     (
@@ -48,32 +248,733 @@ impl CheckerResult {
                 .collect()
         };
 
-        let Package {
-            dependencies,
-            dev_dependencies,
-            ..
-        } = package;
+        let (hoisted_dependencies, warnings) =
+            load_hoisted_dependencies(config.get_directory(), config.get_hoisted_scopes());
 
-        let filter_dependencies = |deps: DepsSet| {
-            deps.into_iter()
-                .filter(|(dependency, _)| !ignore_matches.is_match(dependency.as_str()))
-                .filter(|(dependency, _)| !using_dependencies.contains_key(dependency.as_str()))
-                .filter(|(dependency, _)| {
-                    !config.ignore_bin_package()
-                        || !is_bin_dependency(config.get_directory(), dependency)
+        let filter_dependencies = |deps: &DepsSet| -> HashSet<String> {
+            deps.keys()
+                .filter(|dependency| !ignore_matches.is_match(dependency.as_str()))
+                .filter(|dependency| !using_dependencies.contains_key(dependency.as_str()))
+                .filter(|dependency| !hoisted_dependencies.contains(dependency.as_str()))
+                .filter(|dependency| {
+                    !config.ignore_bin_package() || !bin_dependencies.contains(dependency)
                 })
-                .map(|(dependency, _)| dependency)
+                .cloned()
                 .collect()
         };
 
-        let unused_dependencies = filter_dependencies(dependencies);
-        let unused_dev_dependencies = filter_dependencies(dev_dependencies);
+        let unused_dependencies = filter_dependencies(&package.dependencies);
+        let unused_dev_dependencies = filter_dependencies(&package.dev_dependencies)
+            .into_iter()
+            .filter(|dependency| {
+                !config.suppress_common_devtools() || !is_common_devtool(dependency)
+            })
+            .collect();
 
         CheckerResult {
             using_dependencies,
             missing_dependencies,
             unused_dependencies,
             unused_dev_dependencies,
+            import_counts: BTreeMap::new(),
+            dynamic_usage: Vec::new(),
+            files_scanned: 0,
+            parse_errors: Vec::new(),
+            reexported_only: BTreeSet::new(),
+            warnings,
+            expired_baseline: Baseline::default(),
+            package,
+            directory: config.get_directory().to_path_buf(),
+            max_files_per_dependency: config.get_max_files_per_dependency(),
+            preserve_manifest_order: config.preserve_manifest_order(),
+        }
+    }
+}
+
+impl CheckerResult {
+    pub(crate) fn with_import_counts(mut self, import_counts: BTreeMap<String, usize>) -> Self {
+        self.import_counts = import_counts;
+        self
+    }
+
+    pub(crate) fn with_dynamic_usage(mut self, dynamic_usage: Vec<DynamicUsage>) -> Self {
+        self.dynamic_usage = dynamic_usage;
+        self
+    }
+
+    pub(crate) fn with_files_scanned(mut self, files_scanned: usize) -> Self {
+        self.files_scanned = files_scanned;
+        self
+    }
+
+    pub(crate) fn with_parse_errors(mut self, parse_errors: Vec<String>) -> Self {
+        self.parse_errors = parse_errors;
+        self
+    }
+
+    /// Get sorted files which failed to parse, so their dependencies may be missed.
+    pub fn get_parse_errors(&self) -> &[String] {
+        &self.parse_errors
+    }
+
+    /// Get warnings about the config which didn't fail the check.
+    pub fn get_warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Add warnings found after the check, like of referenced projects which aren't checked.
+    pub(crate) fn extend_warnings(&mut self, warnings: impl IntoIterator<Item = String>) {
+        self.warnings.extend(warnings);
+    }
+
+    pub(crate) fn with_reexported_only(mut self, reexported_only: BTreeSet<String>) -> Self {
+        self.reexported_only = reexported_only;
+        self
+    }
+
+    /// Get dependencies which appear only in `export ... from` statements and never in
+    /// regular imports, so a package just passes them through to its consumers.
+    pub fn reexported_only_dependencies(&self) -> BTreeSet<&str> {
+        self.reexported_only.iter().map(String::as_str).collect()
+    }
+
+    /// Check if the package is `private`, so checks of published packages may be relaxed.
+    pub fn package_is_private(&self) -> bool {
+        self.package.private
+    }
+
+    /// Get code evaluated at runtime, dependencies required by it are not detected.
+    pub fn get_dynamic_usage(&self) -> &[DynamicUsage] {
+        &self.dynamic_usage
+    }
+
+    /// Suppress findings listed in a baseline, unless their `until` date is before `today`.
+    pub(crate) fn with_baseline(mut self, baseline: &Baseline, today: &str) -> Self {
+        let (missing, expired_missing) = partition_baseline(&baseline.missing, today);
+        let (unused, expired_unused) = partition_baseline(&baseline.unused, today);
+        let (unused_dev, expired_unused_dev) = partition_baseline(&baseline.unused_dev, today);
+
+        self.missing_dependencies
+            .retain(|dependency, _| !missing.contains(dependency.as_str()));
+        self.unused_dependencies
+            .retain(|dependency| !unused.contains(dependency.as_str()));
+        self.unused_dev_dependencies
+            .retain(|dependency| !unused_dev.contains(dependency.as_str()));
+
+        self.expired_baseline = Baseline {
+            missing: expired_missing,
+            unused: expired_unused,
+            unused_dev: expired_unused_dev,
+        };
+
+        self
+    }
+
+    /// Get baseline entries whose `until` date has passed, their findings are reported again.
+    pub fn get_expired_baseline(&self) -> &Baseline {
+        &self.expired_baseline
+    }
+
+    /// Get all results as a single serializable report, file lists of dependencies are
+    /// truncated by `Config::with_max_files_per_dependency`.
+    pub fn report(&self) -> Report {
+        let sort_files = |dependencies: &BTreeMap<String, HashSet<String>>| {
+            dependencies
+                .iter()
+                .map(|(dependency, files)| {
+                    let mut files = files.iter().cloned().collect::<Vec<_>>();
+                    files.sort();
+
+                    let files = match self.max_files_per_dependency {
+                        Some(max_files) => truncate_files(files, max_files),
+                        None => files,
+                    };
+
+                    (dependency.to_owned(), files)
+                })
+                .collect()
+        };
+
+        Report {
+            missing: sort_files(&self.missing_dependencies),
+            unused: self.unused_dependencies.iter().cloned().collect(),
+            unused_dev: self.unused_dev_dependencies.iter().cloned().collect(),
+            using: sort_files(&self.using_dependencies),
+            summary: self.summary(),
+        }
+    }
+
+    /// Get numbers of findings and of scanned files.
+    pub fn summary(&self) -> Summary {
+        Summary {
+            missing: self.missing_dependencies.len(),
+            unused: self.unused_dependencies.len(),
+            unused_dev: self.unused_dev_dependencies.len(),
+            files_scanned: self.files_scanned,
         }
     }
+
+    /// Get the alphabetically first file of each missing dependency for compact output.
+    pub fn missing_dependencies_brief(&self) -> BTreeMap<&str, &str> {
+        self.missing_dependencies
+            .iter()
+            .filter_map(|(dependency, files)| {
+                let file = files.iter().min()?;
+                Some((dependency.as_str(), file.as_str()))
+            })
+            .collect()
+    }
+
+    /// Get unused dependencies sorted alphabetically, or in the order of `package.json` when
+    /// `Config::with_preserve_manifest_order` is set, so edits of the manifest diff minimally.
+    pub fn get_unused_dependencies_sorted(&self) -> Vec<&str> {
+        let mut unused_dependencies = self
+            .unused_dependencies
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+
+        unused_dependencies.sort_unstable();
+
+        if self.preserve_manifest_order {
+            let declaration_order = self.package.get_declaration_order();
+            // dependencies missing from the order, like locked ones, go last
+            unused_dependencies.sort_by_key(|dependency| {
+                declaration_order
+                    .iter()
+                    .position(|declared| declared == dependency)
+                    .unwrap_or(usize::MAX)
+            });
+        }
+
+        unused_dependencies
+    }
+
+    /// Get `@types/*` packages listed in `dependencies` rather than in `devDependencies`.
+    pub fn get_misplaced_type_packages(&self) -> Vec<&str> {
+        self.package
+            .dependencies
+            .keys()
+            .filter(|dependency| dependency.starts_with("@types/"))
+            .map(|dependency| dependency.as_str())
+            .collect()
+    }
+
+    /// Get unused `@types/*` packages whose typed packages aren't used either, with reasons
+    /// telling whether the typed package is absent from the manifest or declared but unused.
+    ///
+    /// `@types/node` types core modules rather than a package, so it's never reported.
+    pub fn get_orphan_type_packages(&self) -> Vec<(&str, OrphanTypeReason)> {
+        let mut type_packages = [&self.package.dependencies, &self.package.dev_dependencies]
+            .into_iter()
+            .flat_map(|deps| deps.keys())
+            .filter(|dependency| dependency.as_str() != "@types/node")
+            .filter(|dependency| !self.using_dependencies.contains_key(dependency.as_str()))
+            .filter_map(|dependency| {
+                let typed_package = extract_typed_package(dependency)?;
+
+                if self.using_dependencies.contains_key(&typed_package) {
+                    return None;
+                }
+
+                let reason = if self.package.is_any_dependency(&typed_package) {
+                    OrphanTypeReason::BaseUnused
+                } else {
+                    OrphanTypeReason::BaseAbsent
+                };
+
+                Some((dependency.as_str(), reason))
+            })
+            .collect::<Vec<_>>();
+
+        type_packages.sort_unstable();
+        type_packages.dedup();
+        type_packages
+    }
+
+    /// Get `dependencies` used only by files matching globs of dev files like `**/*.test.ts`,
+    /// so they could be moved to `devDependencies`.
+    ///
+    /// Globs are matched against file paths of `using_dependencies`, malformed globs are skipped.
+    pub fn prod_deps_used_only_in_dev(&self, dev_globs: &[String]) -> Vec<&str> {
+        let mut builder = GlobSetBuilder::new();
+
+        for dev_glob in dev_globs {
+            match Glob::new(dev_glob) {
+                Ok(glob) => {
+                    builder.add(glob);
+                }
+                Err(error) => log::warn!("malformed dev glob {}: {:#?}", dev_glob, error),
+            }
+        }
+
+        let dev_files = match builder.build() {
+            Ok(dev_files) => dev_files,
+            Err(error) => {
+                log::warn!("failed to build dev globs: {:#?}", error);
+                return Vec::new();
+            }
+        };
+
+        self.package
+            .dependencies
+            .keys()
+            .filter(|dependency| {
+                self.using_dependencies
+                    .get(dependency.as_str())
+                    .map_or(false, |files| {
+                        files.iter().all(|file| dev_files.is_match(file))
+                    })
+            })
+            .map(|dependency| dependency.as_str())
+            .collect()
+    }
+
+    /// Get `peerDependencies` never imported, so their declarations may be stale.
+    pub fn get_unused_peer_dependencies(&self) -> Vec<&str> {
+        self.package
+            .peer_dependencies
+            .keys()
+            .filter(|dependency| !self.using_dependencies.contains_key(dependency.as_str()))
+            .map(|dependency| dependency.as_str())
+            .collect()
+    }
+
+    /// Get dependencies declared with versions resolved outside of the registry, like
+    /// `file:../foo` or `github:user/bar`, mapped to their versions for audits.
+    pub fn get_non_registry_dependencies(&self) -> Vec<(&str, &str)> {
+        let mut dependencies = [
+            &self.package.dependencies,
+            &self.package.dev_dependencies,
+            &self.package.optional_dependencies,
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|(_, version)| !is_registry_version(version))
+        .map(|(dependency, version)| (dependency.as_str(), version.as_str()))
+        .collect::<Vec<_>>();
+
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        dependencies
+    }
+
+    /// Get dependencies declared with versions accepting any release, like `*` or `latest`,
+    /// mapped to their versions for audits.
+    pub fn get_loose_version_dependencies(&self) -> Vec<(&str, &str)> {
+        let mut dependencies = [
+            &self.package.dependencies,
+            &self.package.dev_dependencies,
+            &self.package.optional_dependencies,
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|(_, version)| is_loose_version(version))
+        .map(|(dependency, version)| (dependency.as_str(), version.as_str()))
+        .collect::<Vec<_>>();
+
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        dependencies
+    }
+
+    /// Get dependencies usage grouped by `package.json` sections.
+    ///
+    /// Unused `dependencies` and `devDependencies` are the same as `unused_dependencies` and
+    /// `unused_dev_dependencies`, unused peer and optional dependencies are the ones never used.
+    pub fn by_section(&self) -> SectionReport {
+        let get_used = |deps: &DepsSet| {
+            deps.keys()
+                .filter_map(|dependency| {
+                    self.using_dependencies
+                        .get(dependency)
+                        .map(|files| (dependency.to_owned(), files.len()))
+                })
+                .collect()
+        };
+
+        let get_unused = |deps: &DepsSet| {
+            deps.keys()
+                .filter(|dependency| !self.using_dependencies.contains_key(dependency.as_str()))
+                .cloned()
+                .collect()
+        };
+
+        SectionReport {
+            dependencies: SectionUsage {
+                used: get_used(&self.package.dependencies),
+                unused: self.unused_dependencies.iter().cloned().collect(),
+            },
+            dev_dependencies: SectionUsage {
+                used: get_used(&self.package.dev_dependencies),
+                unused: self.unused_dev_dependencies.iter().cloned().collect(),
+            },
+            peer_dependencies: SectionUsage {
+                used: get_used(&self.package.peer_dependencies),
+                unused: get_unused(&self.package.peer_dependencies),
+            },
+            optional_dependencies: SectionUsage {
+                used: get_used(&self.package.optional_dependencies),
+                unused: get_unused(&self.package.optional_dependencies),
+            },
+        }
+    }
+
+    /// Get weights of declared dependencies, the least used dependencies come first.
+    pub fn dependency_weights(&self) -> Vec<(&str, DependencyWeight)> {
+        let dependencies = self
+            .package
+            .dependencies
+            .keys()
+            .chain(self.package.dev_dependencies.keys())
+            .chain(self.package.peer_dependencies.keys())
+            .chain(self.package.optional_dependencies.keys())
+            .map(|dependency| dependency.as_str())
+            .collect::<BTreeSet<_>>();
+
+        let mut weights = dependencies
+            .into_iter()
+            .map(|dependency| {
+                let weight = DependencyWeight {
+                    files: self
+                        .using_dependencies
+                        .get(dependency)
+                        .map_or(0, |files| files.len()),
+                    imports: self.import_counts.get(dependency).copied().unwrap_or(0),
+                };
+                (dependency, weight)
+            })
+            .collect::<Vec<_>>();
+
+        weights.sort_by_key(|(dependency, weight)| (*weight, *dependency));
+        weights
+    }
+
+    /// Get `dependencies` and `devDependencies` which aren't installed under `node_modules`.
+    ///
+    /// Peer and optional dependencies may be missing by design, so they aren't checked.
+    pub fn get_uninstalled_dependencies(&self) -> Vec<&str> {
+        let node_modules = self.directory.join("node_modules");
+
+        let mut dependencies = self
+            .package
+            .dependencies
+            .keys()
+            .chain(self.package.dev_dependencies.keys())
+            .filter(|dependency| load_module(&node_modules.join(dependency)).is_err())
+            .map(|dependency| dependency.as_str())
+            .collect::<Vec<_>>();
+
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        dependencies
+    }
+
+    /// Get packages installed at more than one version under `node_modules`, including
+    /// nested `node_modules` of installed packages up to a limited depth.
+    ///
+    /// Key is a package name and value is install paths relative to the directory with
+    /// versions, sorted by paths.
+    pub fn get_duplicate_installs(&self) -> BTreeMap<String, Vec<(PathBuf, String)>> {
+        let mut installs = collect_installs(&self.directory);
+
+        installs.retain(|_, installs| {
+            let versions = installs
+                .iter()
+                .map(|(_, version)| version)
+                .collect::<BTreeSet<_>>();
+            versions.len() > 1
+        });
+
+        for installs in installs.values_mut() {
+            installs.sort();
+        }
+
+        installs
+    }
+
+    /// Get `main`, `module`, `types`, `bin` and `exports` entry files which don't exist.
+    ///
+    /// Key is a manifest field like `bin.cli` or `exports["./feature"].import`
+    /// and value is the file path as written in the manifest.
+    pub fn get_missing_entry_files(&self) -> Vec<(String, PathBuf)> {
+        let mut entry_files = Vec::new();
+
+        let fields = [
+            ("main", &self.package.main),
+            ("module", &self.package.module),
+            ("types", &self.package.types),
+        ];
+
+        for (field, file) in fields {
+            if let Some(file) = file {
+                entry_files.push((field.to_owned(), file.to_owned()));
+            }
+        }
+
+        for (name, file) in self.package.bin.iter().flatten() {
+            entry_files.push((format!("bin.{name}"), file.to_owned()));
+        }
+
+        if let Some(exports) = &self.package.exports {
+            collect_export_targets(exports, "exports".to_owned(), &mut entry_files);
+        }
+
+        entry_files
+            .into_iter()
+            .filter(|(_, file)| !file.contains('*'))
+            .filter(|(field, file)| {
+                let path = self.directory.join(file);
+                if field == "main" {
+                    !is_existing_main_file(&path)
+                } else {
+                    !path.is_file()
+                }
+            })
+            .map(|(field, file)| (field, PathBuf::from(file)))
+            .collect()
+    }
+
+    /// Get targets of nested subpaths and conditions of the `exports` map which won't
+    /// resolve, like missing files or `types` conditions targeting JavaScript files.
+    ///
+    /// Each target gets at most one issue, `null` targets excluding subpaths are skipped.
+    pub fn get_exports_issues(&self) -> Vec<ExportsIssue> {
+        let mut targets = Vec::new();
+
+        if let Some(exports) = &self.package.exports {
+            collect_export_targets(exports, "exports".to_owned(), &mut targets);
+        }
+
+        targets
+            .into_iter()
+            .filter_map(|(field, target)| {
+                let kind = if !target.starts_with("./") {
+                    ExportsIssueKind::NonRelativeTarget
+                } else if !is_existing_export_target(&self.directory, &target) {
+                    ExportsIssueKind::MissingTarget
+                } else if is_types_condition(&field)
+                    && !DECLARATION_EXTENSIONS
+                        .iter()
+                        .any(|extension| target.ends_with(extension))
+                {
+                    ExportsIssueKind::NonDeclarationTypes
+                } else {
+                    return None;
+                };
+
+                Some(ExportsIssue {
+                    field,
+                    target,
+                    kind,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Get a Graphviz DOT graph of `using_dependencies` with "used-by" edges from dependency
+/// nodes to file nodes, for rendering which files cluster around which dependencies.
+pub fn to_dot(result: &CheckerResult) -> String {
+    let files = result
+        .using_dependencies
+        .values()
+        .flatten()
+        .collect::<BTreeSet<_>>();
+
+    let mut dot = String::from("digraph dependencies {\n");
+
+    for dependency in result.using_dependencies.keys() {
+        let id = escape_dot(&format!("dependency:{dependency}"));
+        let label = escape_dot(dependency);
+        dot.push_str(&format!("    \"{id}\" [label=\"{label}\", shape=box];\n"));
+    }
+
+    for file in &files {
+        let id = escape_dot(&format!("file:{file}"));
+        let label = escape_dot(file);
+        dot.push_str(&format!("    \"{id}\" [label=\"{label}\"];\n"));
+    }
+
+    for (dependency, dependency_files) in &result.using_dependencies {
+        let dependency_id = escape_dot(&format!("dependency:{dependency}"));
+
+        for file in dependency_files.iter().collect::<BTreeSet<_>>() {
+            let file_id = escape_dot(&format!("file:{file}"));
+            dot.push_str(&format!(
+                "    \"{dependency_id}\" -> \"{file_id}\" [label=\"used-by\"];\n"
+            ));
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Get a line diffable text report of missing dependencies with their first files,
+/// unused dependencies and unused dev dependencies, each section sorted by name.
+pub fn to_text(result: &CheckerResult) -> String {
+    let missing = result
+        .missing_dependencies_brief()
+        .into_iter()
+        .map(|(dependency, file)| format!("{dependency} ({file})"))
+        .collect::<Vec<_>>();
+
+    let unused = result
+        .unused_dependencies
+        .iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let unused_dev = result
+        .unused_dev_dependencies
+        .iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let sections = [
+        ("Missing dependencies", missing),
+        ("Unused dependencies", unused),
+        ("Unused devDependencies", unused_dev),
+    ];
+
+    let mut text = String::new();
+
+    for (title, entries) in sections {
+        if entries.is_empty() {
+            text.push_str(&format!("{title}: none\n"));
+            continue;
+        }
+
+        text.push_str(&format!("{title}:\n"));
+        for entry in entries {
+            text.push_str(&format!("  {entry}\n"));
+        }
+    }
+
+    text
+}
+
+/// Escape a quoted DOT identifier, so quotes, backslashes and line breaks stay in the label.
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Keep first files of a sorted list and replace the rest with a trailing `...N more` entry.
+fn truncate_files(files: Vec<String>, max_files: usize) -> Vec<String> {
+    if files.len() <= max_files {
+        return files;
+    }
+
+    let more = files.len() - max_files;
+
+    files
+        .into_iter()
+        .take(max_files)
+        .chain(iter::once(format!("...{} more", more)))
+        .collect()
+}
+
+/// Split baseline entries to names of active suppressions and expired entries.
+fn partition_baseline<'a>(
+    entries: &'a [BaselineEntry],
+    today: &str,
+) -> (HashSet<&'a str>, Vec<BaselineEntry>) {
+    let (expired, active): (Vec<_>, Vec<_>) =
+        entries.iter().partition(|entry| entry.is_expired(today));
+
+    (
+        active
+            .into_iter()
+            .map(|entry| entry.name.as_str())
+            .collect(),
+        expired.into_iter().cloned().collect(),
+    )
+}
+
+/// Collect file targets and `*` patterns of exports conditions and subpaths.
+fn collect_export_targets(exports: &Value, field: String, targets: &mut Vec<(String, String)>) {
+    match exports {
+        Value::String(target) => targets.push((field, target.to_owned())),
+        Value::Array(fallbacks) => {
+            for (index, fallback) in fallbacks.iter().enumerate() {
+                collect_export_targets(fallback, format!("{field}[{index}]"), targets);
+            }
+        }
+        Value::Object(conditions) => {
+            for (key, target) in conditions {
+                let field = if key.starts_with('.') {
+                    format!("{field}[\"{key}\"]")
+                } else {
+                    format!("{field}.{key}")
+                };
+                collect_export_targets(target, field, targets);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Check if an `exports` target is a file, or if any file matches a `*` pattern target.
+fn is_existing_export_target(directory: &Path, target: &str) -> bool {
+    let pattern = target.trim_start_matches("./");
+
+    let (prefix, _) = match pattern.split_once('*') {
+        Some(split) => split,
+        None => return directory.join(pattern).is_file(),
+    };
+
+    // `*` of exports patterns matches any string including `/`
+    let matcher = match Glob::new(pattern) {
+        Ok(glob) => glob.compile_matcher(),
+        Err(_) => return false,
+    };
+
+    let base = prefix.rsplit_once('/').map_or("", |(base, _)| base);
+
+    WalkBuilder::new(directory.join(base))
+        .standard_filters(false)
+        .build()
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_type()
+                .map_or(false, |file_type| file_type.is_file())
+        })
+        .any(|entry| {
+            entry
+                .path()
+                .strip_prefix(directory)
+                .map_or(false, |path| matcher.is_match(path))
+        })
+}
+
+/// Check if a field like `exports["./feature"].types[0]` is a target of a `types` condition.
+fn is_types_condition(field: &str) -> bool {
+    field
+        .trim_end_matches(|char: char| char == '[' || char == ']' || char.is_ascii_digit())
+        .ends_with(".types")
+}
+
+/// Check if a `main` entry resolves to a file like Node.js does it.
+fn is_existing_main_file(path: &Path) -> bool {
+    if path.is_file() || path.join("index.js").is_file() {
+        return true;
+    }
+
+    let file_name = match path.file_name().and_then(|file_name| file_name.to_str()) {
+        Some(file_name) => file_name,
+        None => return false,
+    };
+
+    MAIN_EXTENSIONS.iter().any(|extension| {
+        path.with_file_name(format!("{file_name}.{extension}"))
+            .is_file()
+    })
 }
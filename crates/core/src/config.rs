@@ -1,6 +1,10 @@
 use globset::{self, Glob, GlobSet, GlobSetBuilder};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
+use crate::package::DepsSet;
+use crate::parser::SyntaxFeatures;
+
 /// Config for dependency checker.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
@@ -42,10 +46,132 @@ pub struct Config {
     /// A flag to indicate if depcheck skips calculation of missing dependencies.
     skip_missing: bool,
     /// Path to a file with patterns describing files to ignore.
+    ///
+    /// Files of this name in parent directories apply as well, and like `.gitignore` files
+    /// a file of a nested directory takes precedence, so it may re-include ignored files.
+    /// Ignore patterns take precedence over all of them.
     ignore_path: Option<PathBuf>,
+    /// Names of specials to detect dependencies referenced by tool configs.
+    ///
+    /// Available specials: `babel`, `cypress`, `graphql-codegen`, `import-map`, `jest`,
+    /// `module-federation`, `openapi`, `playwright`, `proto`, `provide-plugin`, `renovate`,
+    /// `shell`, `vitest`, `workbox`.
+    specials: Vec<String>,
+    /// Names of packages under `node_modules` to check as a part of the directory.
+    include_modules: Vec<String>,
+    /// A flag to indicate if depcheck checks files sequentially in the current thread.
+    single_threaded: bool,
+    /// A flag to indicate if depcheck follows symbolic links, links resolved outside of
+    /// the checked directory are skipped.
+    follow_symlinks: bool,
+    /// A flag to indicate if depcheck treats common dev tools like `typescript` or `eslint`
+    /// in dev dependencies as used.
+    suppress_common_devtools: bool,
+    /// Import path prefixes of `.proto` files mapped to packages providing them,
+    /// used by the `proto` special.
+    ///
+    /// Default value: `google/protobuf/` is mapped to `google-protobuf`.
+    proto_package_map: HashMap<String, String>,
+    /// Extensions of templates like `php`, `erb` or `html.twig` whose inline `<script>`
+    /// elements are checked as JavaScript.
+    embedded_script_extensions: Vec<String>,
+    /// A source of declared dependencies.
+    dependency_source: DependencySource,
+    /// Overlay manifests whose dependency sections are merged into `package.json` in order,
+    /// a relative path is resolved against the directory.
+    ///
+    /// Sections are unioned, a version of a later manifest overrides a version of the same
+    /// dependency of the section, while other fields like `name` and `type` are kept of
+    /// `package.json`. A dependency declared by different sections stays in all of them.
+    manifest_paths: Vec<PathBuf>,
+    /// A base of file paths of the result, keys of `Checker::check_dead_imports` and
+    /// `Checker::collect_specifiers` are always relative to the directory.
+    path_base: PathBase,
+    /// Workspace packages like `packages/ui` or `packages/*` whose dependencies are hoisted
+    /// to the directory, so they aren't reported as unused.
+    hoisted_scopes: Vec<String>,
+    /// Dot separated paths of `package.json` fields like `config.commitizen.path`
+    /// whose values are package names treated as used.
+    manifest_package_fields: Vec<String>,
+    /// Syntax proposals enabled while parsing JavaScript and TypeScript files.
+    syntax_features: SyntaxFeatures,
+    /// Extensions like `ts` of files to scan, other files are skipped while walking
+    /// unless they're matched by an enabled special. All files are scanned by default.
+    scan_extensions: Option<HashSet<String>>,
+    /// Globs of config files like `app.config.js` mapped to names of arrays like `plugins`
+    /// whose string literals naming installed packages are treated as used.
+    plugin_arrays: Vec<(String, Vec<String>)>,
+    /// Scan only files tracked by git, so untracked files are skipped even when they aren't
+    /// ignored. Directories outside of git repositories are walked as usual.
+    git_tracked_only: bool,
+    /// Maximum number of files listed for each dependency of reports, the remaining files
+    /// are replaced with a `...N more` entry. All files are listed by default.
+    max_files_per_dependency: Option<usize>,
+    /// Sort dependencies of sorted getters like `get_unused_dependencies_sorted` in the order
+    /// they're declared by `package.json` rather than alphabetically.
+    preserve_manifest_order: bool,
+    /// Check projects referenced by `references` of `tsconfig.json` by `Checker::check_projects`
+    /// as well, following references of the referenced projects.
+    follow_project_references: bool,
+    /// Scopes like `@org` whose packages aren't reported as missing while they aren't installed,
+    /// like workspace packages which aren't built yet.
+    unresolved_internal_scopes: Vec<String>,
+    /// Scan hidden files and directories like `.eslintrc.js` and `.config/`, which are skipped
+    /// by default. Version control directories like `.git` are skipped anyway.
+    scan_hidden: bool,
+    /// Fail `Checker::check_package` when a file of a parsed extension fails to parse,
+    /// rather than skipping the file.
+    fail_on_parse_error: bool,
+}
+
+/// A source of declared dependencies.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum DependencySource {
+    /// Dependencies of `package.json`, which is the default.
+    #[default]
+    Manifest,
+    /// Direct dependencies of a lockfile, a relative path is resolved against the directory.
+    ///
+    /// Only `package-lock.json` of version 2 or later is supported.
+    Lockfile(PathBuf),
+    /// Explicit dependencies declared instead of all sections of `package.json`,
+    /// like for checking what would be missing once some dependencies are removed.
+    Explicit(DepsSet),
+}
+
+/// A base of file paths of the result.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PathBase {
+    /// Paths relative to the directory, which is the default.
+    #[default]
+    Directory,
+    /// Absolute paths joined from the directory.
+    Absolute,
 }
 
 impl Config {
+    /// Get a config checking a nested package on its own, `package_dir` is the package
+    /// directory or its `package.json`.
+    ///
+    /// The manifest, `node_modules` and scanned files are all resolved against the package
+    /// directory, like directories of `Config::new`.
+    pub fn for_package_dir(package_dir: PathBuf) -> Self {
+        let is_manifest = package_dir.is_file()
+            && package_dir
+                .file_name()
+                .map_or(false, |file_name| file_name == "package.json");
+
+        let directory = match package_dir.parent() {
+            Some(directory) if is_manifest && directory.as_os_str().is_empty() => {
+                PathBuf::from(".")
+            }
+            Some(directory) if is_manifest => directory.to_path_buf(),
+            _ => package_dir,
+        };
+
+        Config::new(directory)
+    }
+
     pub fn new(directory: PathBuf) -> Self {
         let ignore_patterns = [
             r".git",
@@ -84,11 +210,41 @@ impl Config {
             ignore_bin_package: false,
             ignore_matches: Vec::new(),
             ignore_path: None,
+            specials: Vec::new(),
+            include_modules: Vec::new(),
+            single_threaded: false,
+            follow_symlinks: false,
+            suppress_common_devtools: false,
+            proto_package_map: HashMap::from([(
+                String::from("google/protobuf/"),
+                String::from("google-protobuf"),
+            )]),
+            embedded_script_extensions: Vec::new(),
+            dependency_source: DependencySource::Manifest,
+            manifest_paths: Vec::new(),
+            path_base: PathBase::Directory,
+            hoisted_scopes: Vec::new(),
+            manifest_package_fields: Vec::new(),
+            syntax_features: SyntaxFeatures::default(),
+            scan_extensions: None,
+            plugin_arrays: Vec::new(),
+            git_tracked_only: false,
+            max_files_per_dependency: None,
+            preserve_manifest_order: false,
+            follow_project_references: false,
+            unresolved_internal_scopes: Vec::new(),
+            scan_hidden: false,
+            fail_on_parse_error: false,
         }
     }
 }
 
 impl Config {
+    pub fn with_directory(mut self, directory: PathBuf) -> Self {
+        self.directory = directory;
+        self
+    }
+
     pub fn with_ignore_patterns(mut self, ignore_patterns: Vec<String>) -> Self {
         self.ignore_patterns = ignore_patterns;
         self
@@ -114,6 +270,125 @@ impl Config {
         self
     }
 
+    pub fn with_specials(mut self, specials: Vec<String>) -> Self {
+        self.specials = specials;
+        self
+    }
+
+    pub fn with_include_modules(mut self, include_modules: Vec<String>) -> Self {
+        self.include_modules = include_modules;
+        self
+    }
+
+    pub fn with_single_threaded(mut self, single_threaded: bool) -> Self {
+        self.single_threaded = single_threaded;
+        self
+    }
+
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    pub fn with_suppress_common_devtools(mut self, suppress_common_devtools: bool) -> Self {
+        self.suppress_common_devtools = suppress_common_devtools;
+        self
+    }
+
+    pub fn with_proto_package_map(mut self, proto_package_map: HashMap<String, String>) -> Self {
+        self.proto_package_map = proto_package_map;
+        self
+    }
+
+    pub fn with_embedded_script_extensions(
+        mut self,
+        embedded_script_extensions: Vec<String>,
+    ) -> Self {
+        self.embedded_script_extensions = embedded_script_extensions;
+        self
+    }
+
+    pub fn with_dependency_source(mut self, dependency_source: DependencySource) -> Self {
+        self.dependency_source = dependency_source;
+        self
+    }
+
+    pub fn with_manifest_paths(mut self, manifest_paths: Vec<PathBuf>) -> Self {
+        self.manifest_paths = manifest_paths;
+        self
+    }
+
+    pub fn with_path_base(mut self, path_base: PathBase) -> Self {
+        self.path_base = path_base;
+        self
+    }
+
+    pub fn with_hoisted_scopes(mut self, hoisted_scopes: Vec<String>) -> Self {
+        self.hoisted_scopes = hoisted_scopes;
+        self
+    }
+
+    pub fn with_manifest_package_fields(mut self, manifest_package_fields: Vec<String>) -> Self {
+        self.manifest_package_fields = manifest_package_fields;
+        self
+    }
+
+    pub fn with_syntax_features(mut self, syntax_features: SyntaxFeatures) -> Self {
+        self.syntax_features = syntax_features;
+        self
+    }
+
+    pub fn with_scan_extensions(mut self, scan_extensions: Option<HashSet<String>>) -> Self {
+        self.scan_extensions = scan_extensions;
+        self
+    }
+
+    pub fn with_plugin_arrays(mut self, plugin_arrays: Vec<(String, Vec<String>)>) -> Self {
+        self.plugin_arrays = plugin_arrays;
+        self
+    }
+
+    pub fn with_git_tracked_only(mut self, git_tracked_only: bool) -> Self {
+        self.git_tracked_only = git_tracked_only;
+        self
+    }
+
+    pub fn with_max_files_per_dependency(
+        mut self,
+        max_files_per_dependency: Option<usize>,
+    ) -> Self {
+        self.max_files_per_dependency = max_files_per_dependency;
+        self
+    }
+
+    pub fn with_preserve_manifest_order(mut self, preserve_manifest_order: bool) -> Self {
+        self.preserve_manifest_order = preserve_manifest_order;
+        self
+    }
+
+    pub fn with_follow_project_references(mut self, follow_project_references: bool) -> Self {
+        self.follow_project_references = follow_project_references;
+        self
+    }
+
+    pub fn with_unresolved_internal_scopes(
+        mut self,
+        unresolved_internal_scopes: Vec<String>,
+    ) -> Self {
+        self.unresolved_internal_scopes = unresolved_internal_scopes;
+        self
+    }
+
+    pub fn with_scan_hidden(mut self, scan_hidden: bool) -> Self {
+        self.scan_hidden = scan_hidden;
+        self
+    }
+
+    pub fn with_fail_on_parse_error(mut self, fail_on_parse_error: bool) -> Self {
+        self.fail_on_parse_error = fail_on_parse_error;
+        self
+    }
+
     pub fn ignore_bin_package(&self) -> bool {
         self.ignore_bin_package
     }
@@ -122,9 +397,25 @@ impl Config {
         self.skip_missing
     }
 
+    pub fn single_threaded(&self) -> bool {
+        self.single_threaded
+    }
+
+    pub fn follow_symlinks(&self) -> bool {
+        self.follow_symlinks
+    }
+
+    pub fn suppress_common_devtools(&self) -> bool {
+        self.suppress_common_devtools
+    }
+
     pub fn ignore_path(&self) -> Option<&Path> {
         self.ignore_path.as_deref()
     }
+
+    pub fn is_special_enabled(&self, special: &str) -> bool {
+        self.specials.iter().any(|enabled| enabled == special)
+    }
 }
 
 impl Config {
@@ -142,6 +433,78 @@ impl Config {
         builder.build()
     }
 
+    pub fn get_include_modules(&self) -> &Vec<String> {
+        &self.include_modules
+    }
+
+    pub fn get_proto_package_map(&self) -> &HashMap<String, String> {
+        &self.proto_package_map
+    }
+
+    pub fn get_embedded_script_extensions(&self) -> &Vec<String> {
+        &self.embedded_script_extensions
+    }
+
+    pub fn get_dependency_source(&self) -> &DependencySource {
+        &self.dependency_source
+    }
+
+    pub fn get_manifest_paths(&self) -> &Vec<PathBuf> {
+        &self.manifest_paths
+    }
+
+    pub fn get_path_base(&self) -> PathBase {
+        self.path_base
+    }
+
+    pub fn get_hoisted_scopes(&self) -> &Vec<String> {
+        &self.hoisted_scopes
+    }
+
+    pub fn get_manifest_package_fields(&self) -> &Vec<String> {
+        &self.manifest_package_fields
+    }
+
+    pub fn get_syntax_features(&self) -> SyntaxFeatures {
+        self.syntax_features
+    }
+
+    pub fn get_scan_extensions(&self) -> Option<&HashSet<String>> {
+        self.scan_extensions.as_ref()
+    }
+
+    pub fn get_plugin_arrays(&self) -> &Vec<(String, Vec<String>)> {
+        &self.plugin_arrays
+    }
+
+    pub fn git_tracked_only(&self) -> bool {
+        self.git_tracked_only
+    }
+
+    pub fn get_max_files_per_dependency(&self) -> Option<usize> {
+        self.max_files_per_dependency
+    }
+
+    pub fn preserve_manifest_order(&self) -> bool {
+        self.preserve_manifest_order
+    }
+
+    pub fn follow_project_references(&self) -> bool {
+        self.follow_project_references
+    }
+
+    pub fn get_unresolved_internal_scopes(&self) -> &Vec<String> {
+        &self.unresolved_internal_scopes
+    }
+
+    pub fn scan_hidden(&self) -> bool {
+        self.scan_hidden
+    }
+
+    pub fn fail_on_parse_error(&self) -> bool {
+        self.fail_on_parse_error
+    }
+
     pub fn get_directory(&self) -> &Path {
         &self.directory
     }
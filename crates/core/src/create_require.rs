@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+use swc_atoms::JsWord;
+use swc_ecma_ast::{CallExpr, Callee, Expr, Lit, MemberProp, Module, Pat, VarDeclarator};
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Collects names of bindings of `createRequire(...)` calls like
+/// `const load = createRequire(import.meta.url)`.
+#[derive(Debug, Default)]
+struct RequireBindingsCollector {
+    bindings: HashSet<JsWord>,
+}
+
+impl Visit for RequireBindingsCollector {
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, &declarator.init) {
+            if is_create_require_call(init) {
+                self.bindings.insert(binding.id.sym.clone());
+            }
+        }
+
+        declarator.visit_children_with(self);
+    }
+}
+
+/// Collects literal specifiers of calls of `createRequire(...)` bindings.
+#[derive(Debug)]
+struct CreatedRequiresCollector {
+    bindings: HashSet<JsWord>,
+    specifiers: Vec<String>,
+}
+
+impl Visit for CreatedRequiresCollector {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        let is_created_require = match &call.callee {
+            Callee::Expr(callee) => match &**callee {
+                Expr::Ident(ident) => self.bindings.contains(&ident.sym),
+                callee => is_create_require_call(callee),
+            },
+            _ => false,
+        };
+
+        if let Some(argument) = call.args.first().filter(|_| is_created_require) {
+            match &*argument.expr {
+                Expr::Lit(Lit::Str(specifier)) => self.specifiers.push(specifier.value.to_string()),
+                Expr::Tpl(tpl) if tpl.exprs.is_empty() => self
+                    .specifiers
+                    .extend(tpl.quasis.first().map(|quasi| quasi.raw.to_string())),
+                _ => {}
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+}
+
+/// Check if an expression is a `createRequire(...)` or `module.createRequire(...)` call.
+fn is_create_require_call(expr: &Expr) -> bool {
+    let callee = match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => callee,
+        _ => return false,
+    };
+
+    match &**callee {
+        Expr::Ident(ident) => &*ident.sym == "createRequire",
+        Expr::Member(member) => {
+            matches!(&member.prop, MemberProp::Ident(prop) if &*prop.sym == "createRequire")
+        }
+        _ => false,
+    }
+}
+
+/// Get specifiers required by functions created by `createRequire` of the `module` core module.
+///
+/// Bindings named `require` are skipped, their calls are detected as usual requires.
+pub fn collect_created_requires(module: &Module) -> Vec<String> {
+    let mut bindings_collector = RequireBindingsCollector::default();
+    module.visit_with(&mut bindings_collector);
+
+    let mut bindings = bindings_collector.bindings;
+    bindings.remove(&JsWord::from("require"));
+
+    let mut collector = CreatedRequiresCollector {
+        bindings,
+        specifiers: Vec::new(),
+    };
+    module.visit_with(&mut collector);
+
+    collector.specifiers
+}
@@ -0,0 +1,89 @@
+use regex::Regex;
+use std::path::Path;
+
+/// Extensions of stylesheets scanned for package specifiers.
+const CSS_EXTENSIONS: [&str; 2] = ["css", "less"];
+
+/// Extensions tried by Less while resolving an import without an extension.
+const LESS_EXTENSIONS: [&str; 2] = ["less", "css"];
+
+/// Check if a file is a stylesheet.
+pub fn is_css_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .map_or(false, |extension| CSS_EXTENSIONS.contains(&extension))
+}
+
+/// Check if a file is a Less stylesheet.
+pub fn is_less_file(path: &Path) -> bool {
+    path.extension()
+        .map_or(false, |extension| extension == "less")
+}
+
+/// Get module specifiers referenced by a stylesheet.
+///
+/// Supports `@import "<specifier>"` and `@import url(<specifier>)` rules, Tailwind
+/// `@plugin "<specifier>"` directives and CSS Modules `composes: name from "<specifier>"`
+/// declarations. Remote URLs are skipped.
+pub fn collect_css_specifiers(content: &str) -> Vec<String> {
+    let content = strip_css_comments(content);
+
+    let import_pattern = Regex::new(
+        r#"(?i)@import\s+(?:\([^)]*\)\s*)?(?:url\(\s*["']?([^"')\s]+)["']?\s*\)|["']([^"']+)["'])"#,
+    )
+    .unwrap();
+    let plugin_pattern = Regex::new(r#"(?i)@plugin\s+["']([^"']+)["']"#).unwrap();
+    let composes_pattern =
+        Regex::new(r#"(?i)\bcomposes\s*:[^;}]*?\bfrom\s+["']([^"']+)["']"#).unwrap();
+
+    let imports = import_pattern
+        .captures_iter(&content)
+        .filter_map(|captures| captures.get(1).or_else(|| captures.get(2)));
+    let plugins = plugin_pattern
+        .captures_iter(&content)
+        .filter_map(|captures| captures.get(1));
+    let composes = composes_pattern
+        .captures_iter(&content)
+        .filter_map(|captures| captures.get(1));
+
+    imports
+        .chain(plugins)
+        .chain(composes)
+        .map(|specifier| specifier.as_str())
+        .filter(|specifier| !specifier.contains("://") && !specifier.starts_with("//"))
+        .map(normalize_css_specifier)
+        .collect()
+}
+
+/// Get module specifiers imported by a Less stylesheet in a directory.
+///
+/// Options like `@import (reference) "pkg/styles";` and `//` comments are supported.
+/// Less resolves imports against the directory first, so files found there aren't packages.
+pub fn collect_less_specifiers(content: &str, directory: &Path) -> Vec<String> {
+    let line_comment_pattern = Regex::new(r"(?m)(^|\s)//.*$").unwrap();
+    let content = line_comment_pattern.replace_all(content, "$1");
+
+    collect_css_specifiers(&content)
+        .into_iter()
+        .filter(|specifier| !is_local_less_file(specifier, directory))
+        .collect()
+}
+
+fn is_local_less_file(specifier: &str, directory: &Path) -> bool {
+    let path = directory.join(specifier);
+
+    path.is_file()
+        || LESS_EXTENSIONS
+            .iter()
+            .any(|extension| path.with_extension(extension).is_file())
+}
+
+/// Drop the webpack `~` prefix resolving a specifier from `node_modules`.
+fn normalize_css_specifier(specifier: &str) -> String {
+    specifier.trim_start_matches('~').to_owned()
+}
+
+fn strip_css_comments(content: &str) -> String {
+    let comment_pattern = Regex::new(r"(?s)/\*.*?\*/").unwrap();
+    comment_pattern.replace_all(content, "").into_owned()
+}
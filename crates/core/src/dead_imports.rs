@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use swc_ecma_ast::{Ident, ImportDecl, ImportSpecifier, MemberProp, Module, PropName};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::util::is_external_specifier::is_external_specifier;
+
+/// Collects imported bindings and every identifier referenced outside of imports.
+#[derive(Debug, Default)]
+struct DeadImportsCollector {
+    imports: Vec<(String, Vec<String>)>,
+    references: HashSet<String>,
+}
+
+impl Visit for DeadImportsCollector {
+    fn visit_import_decl(&mut self, import: &ImportDecl) {
+        let specifier = import.src.value.to_string();
+
+        if import.specifiers.is_empty() || !is_external_specifier(&specifier) {
+            return;
+        }
+
+        let bindings = import
+            .specifiers
+            .iter()
+            .map(|specifier| match specifier {
+                ImportSpecifier::Named(named) => named.local.sym.to_string(),
+                ImportSpecifier::Default(default) => default.local.sym.to_string(),
+                ImportSpecifier::Namespace(namespace) => namespace.local.sym.to_string(),
+            })
+            .collect();
+
+        self.imports.push((specifier, bindings));
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.references.insert(ident.sym.to_string());
+    }
+
+    fn visit_member_prop(&mut self, prop: &MemberProp) {
+        if let MemberProp::Computed(computed) = prop {
+            computed.visit_with(self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, name: &PropName) {
+        if let PropName::Computed(computed) = name {
+            computed.visit_with(self);
+        }
+    }
+}
+
+/// Get external import specifiers whose imported bindings are never referenced in a module.
+pub fn collect_dead_imports(module: &Module) -> Vec<String> {
+    let mut collector = DeadImportsCollector::default();
+    module.visit_with(&mut collector);
+
+    let DeadImportsCollector {
+        imports,
+        references,
+    } = collector;
+
+    imports
+        .into_iter()
+        .filter(|(_, bindings)| bindings.iter().all(|binding| !references.contains(binding)))
+        .map(|(specifier, _)| specifier)
+        .collect()
+}
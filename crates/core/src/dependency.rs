@@ -1,23 +1,34 @@
+use crate::checker::SpecifierMapper;
 use crate::config::Config;
 use crate::package::Package;
+use crate::util::extract_package_name::extract_package_name;
 use crate::util::extract_type_name::extract_type_name;
-use crate::util::is_bin_dependency::is_bin_dependency;
+use crate::util::is_bin_dependency::BinDependencies;
 use crate::util::is_core_module::is_core_module;
+use crate::util::is_external_specifier::is_external_specifier;
 use crate::util::load_module::load_module;
-use regex::Regex;
+use serde_json::Value;
 use std::iter;
-use std::path::{Component, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use swc_ecma_dep_graph::{DependencyDescriptor, DependencyKind};
 use swc_ecma_parser::Syntax;
 
+/// Extensions tried while resolving a specifier against `baseUrl`.
+const BASE_URL_EXTENSIONS: [&str; 8] = ["ts", "tsx", "d.ts", "js", "jsx", "mjs", "cjs", "json"];
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Dependency {
-    descriptor: DependencyDescriptor,
+    specifier: String,
+    kind: DependencyKind,
 }
 
 impl Dependency {
     pub fn new(descriptor: DependencyDescriptor) -> Self {
-        Dependency { descriptor }
+        Self::from_specifier(descriptor.specifier.to_string(), descriptor.kind)
+    }
+
+    pub fn from_specifier(specifier: String, kind: DependencyKind) -> Self {
+        Dependency { specifier, kind }
     }
 }
 
@@ -27,28 +38,31 @@ impl Dependency {
         syntax: &Syntax,
         package: &Package,
         config: &Config,
+        bin_dependencies: &BinDependencies,
     ) -> Vec<String> {
         self.get_dependencies(syntax, package)
             .into_iter()
             .filter(|dependency| !is_core_module(dependency.as_str()))
             .filter(|dependency| {
-                !config.ignore_bin_package()
-                    || !is_bin_dependency(config.get_directory(), dependency)
+                !config.ignore_bin_package() || !bin_dependencies.contains(dependency)
             })
             .flat_map(|dependency| {
-                let dependency_module = load_module(
-                    &config
-                        .get_directory()
-                        .join("node_modules")
-                        .join(&dependency),
-                );
+                let node_modules = config.get_directory().join("node_modules");
+                let dependency_module = load_module(&node_modules.join(&dependency));
                 dependency_module
                     .map(|dependency_module| {
+                        // optional peers are used only when they're installed
+                        let is_installed_peer = |peer_dependency: &str| {
+                            !dependency_module.is_optional_peer_dependency(peer_dependency)
+                                || node_modules.join(peer_dependency).is_dir()
+                        };
+
                         iter::once(&dependency)
                             .chain(dependency_module.peer_dependencies.keys().filter(
                                 |&peer_dependency| {
-                                    package.is_dependency(peer_dependency)
-                                        || package.is_dev_dependency(peer_dependency)
+                                    (package.is_dependency(peer_dependency)
+                                        || package.is_dev_dependency(peer_dependency))
+                                        && is_installed_peer(peer_dependency)
                                 },
                             ))
                             .chain(dependency_module.optional_dependencies.keys().filter(
@@ -69,15 +83,17 @@ impl Dependency {
         self.get_dependency()
             .map(|dependency| match syntax {
                 Syntax::Typescript(_) => {
-                    if self.descriptor.kind == DependencyKind::ImportType {
-                        let dependency_type = format!("@types/{dependency}");
-                        if package.is_dependency(&dependency_type)
-                            || package.is_dev_dependency(&dependency_type)
-                        {
-                            vec![dependency_type]
-                        } else {
-                            vec![]
-                        }
+                    if self.kind == DependencyKind::ImportType {
+                        // `typeof import("pkg")` and `import("pkg").Type` reference only
+                        // types, provided either by the package itself or by DefinitelyTyped.
+                        let dependency_type = extract_type_name(&dependency);
+                        [dependency, dependency_type]
+                            .into_iter()
+                            .filter(|dependency| {
+                                package.is_dependency(dependency)
+                                    || package.is_dev_dependency(dependency)
+                            })
+                            .collect()
                     } else {
                         let dependency_type = extract_type_name(&dependency);
                         if package.is_dependency(&dependency_type)
@@ -96,30 +112,193 @@ impl Dependency {
             .unwrap_or_default()
     }
 
-    fn get_dependency(&self) -> Option<String> {
-        let specifier = self.descriptor.specifier.to_string();
-        let scope_pattern = Regex::new(r"^(?:(@[^/]+)[/]+)([^/]+)[/]?").unwrap();
-        let base_pattern = Regex::new(r"^([^/]+)[/]?").unwrap();
-        let scope_pattern_test = Regex::new(r"^@").unwrap();
+    /// Get a package name of the specifier, `lodash/fp` is `lodash`.
+    pub fn get_dependency(&self) -> Option<String> {
+        extract_package_name(&self.specifier)
+    }
 
-        if scope_pattern_test.is_match(&specifier) {
-            let captures = scope_pattern.captures(&specifier)?;
+    /// Replace the specifier with a package name returned by a mapper of its package name,
+    /// the specifier is dropped when the mapper returns `None`.
+    pub fn map_package(self, specifier_mapper: &SpecifierMapper) -> Option<Dependency> {
+        let dependency = match self.get_dependency() {
+            Some(dependency) => dependency,
+            None => return Some(self),
+        };
 
-            return match (captures.get(1), captures.get(2)) {
-                (Some(first), Some(second)) => {
-                    Some(first.as_str().to_owned() + "/" + second.as_str())
-                }
-                _ => None,
-            };
-        } else {
-            let captures = base_pattern.captures(&specifier)?;
-            captures.get(1).map(|v| v.as_str().to_owned())
+        specifier_mapper(&dependency).map(|specifier| Dependency {
+            specifier,
+            kind: self.kind,
+        })
+    }
+
+    /// Resolve a `#` subpath import like `#crypto` to targets mapped by the package `imports`.
+    ///
+    /// Other dependencies are returned as is.
+    pub fn resolve_subpath_imports(self, package: &Package) -> Vec<Dependency> {
+        if !self.specifier.starts_with('#') {
+            return vec![self];
         }
+
+        let imports = match package.imports.as_ref().and_then(Value::as_object) {
+            Some(imports) => imports,
+            None => return Vec::new(),
+        };
+
+        let (target, pattern_match) = match imports.get(&self.specifier) {
+            Some(target) => (target, None),
+            None => match imports.iter().find_map(|(key, target)| {
+                match_subpath_pattern(key, &self.specifier).map(|matched| (target, Some(matched)))
+            }) {
+                Some(matched) => matched,
+                None => return Vec::new(),
+            },
+        };
+
+        let mut targets = Vec::new();
+        collect_subpath_targets(target, &mut targets);
+
+        targets
+            .into_iter()
+            .map(|target| match pattern_match {
+                Some(matched) => target.replace('*', matched),
+                None => target,
+            })
+            .map(|specifier| Dependency::from_specifier(specifier, self.kind))
+            .collect()
+    }
+
+    /// Resolve a specifier matched by a tsconfig `paths` alias like `@utils/*`.
+    ///
+    /// Targets inside `node_modules` are replaced with their package specifiers, while an alias
+    /// resolving to an existing local file is internal and dropped. Other dependencies and
+    /// aliases of missing targets, which fall back to module resolution, are returned as is.
+    pub fn resolve_path_aliases(self, path_aliases: &[(String, Vec<PathBuf>)]) -> Vec<Dependency> {
+        let matched = path_aliases.iter().find_map(|(alias, targets)| {
+            if alias == &self.specifier {
+                Some((targets, None))
+            } else {
+                match_subpath_pattern(alias, &self.specifier)
+                    .map(|matched| (targets, Some(matched)))
+            }
+        });
+
+        let (targets, pattern_match) = match matched {
+            Some(matched) => matched,
+            None => return vec![self],
+        };
+
+        let targets = targets
+            .iter()
+            .map(|target| match (pattern_match, target.to_str()) {
+                (Some(matched), Some(target)) => PathBuf::from(target.replace('*', matched)),
+                _ => target.to_path_buf(),
+            })
+            .collect::<Vec<_>>();
+
+        let packages = targets
+            .iter()
+            .filter_map(|target| get_node_modules_specifier(target))
+            .map(|specifier| Dependency::from_specifier(specifier, self.kind))
+            .collect::<Vec<_>>();
+
+        if !packages.is_empty() {
+            return packages;
+        }
+
+        if targets.iter().any(|target| is_existing_module(target)) {
+            return Vec::new();
+        }
+
+        vec![self]
     }
 
     pub fn is_external(&self) -> bool {
-        let path = PathBuf::from(self.descriptor.specifier.to_string());
-        let root_component = path.components().next();
-        matches!(root_component, Some(Component::Normal(_)))
+        is_external_specifier(&self.specifier)
+    }
+
+    /// Check if the dependency is re-exported like `export { x } from 'pkg'`.
+    pub fn is_reexport(&self) -> bool {
+        matches!(
+            self.kind,
+            DependencyKind::Export | DependencyKind::ExportType
+        )
+    }
+
+    /// Check if a non-relative specifier resolves to a file or a directory under `baseUrl`.
+    pub fn is_base_url_module(&self, base_url: &Path) -> bool {
+        let first_segment = match self.specifier.split('/').next() {
+            Some(first_segment) if !first_segment.is_empty() => first_segment,
+            _ => return false,
+        };
+
+        let path = base_url.join(first_segment);
+
+        path.exists()
+            || BASE_URL_EXTENSIONS.iter().any(|extension| {
+                base_url
+                    .join(format!("{first_segment}.{extension}"))
+                    .is_file()
+            })
+    }
+}
+
+/// Get a specifier of a path inside `node_modules` like `some-pkg/lib/debounce`
+/// of `node_modules/some-pkg/lib/debounce`.
+fn get_node_modules_specifier(path: &Path) -> Option<String> {
+    let components = path.components().collect::<Vec<_>>();
+    let position = components
+        .iter()
+        .rposition(|component| component.as_os_str() == "node_modules")?;
+
+    let segments = components[position + 1..]
+        .iter()
+        .map(|component| match component {
+            Component::Normal(segment) => segment.to_str(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(segments.join("/")).filter(|specifier| !specifier.is_empty())
+}
+
+/// Check if a path is a file or a directory, or a file once extensions are added.
+fn is_existing_module(path: &Path) -> bool {
+    let file_name = match path.file_name().and_then(|file_name| file_name.to_str()) {
+        Some(file_name) => file_name,
+        None => return false,
+    };
+
+    path.exists()
+        || BASE_URL_EXTENSIONS.iter().any(|extension| {
+            path.with_file_name(format!("{file_name}.{extension}"))
+                .is_file()
+        })
+}
+
+/// Get the part of a specifier matched by `*` of a subpath pattern like `#dep/*`.
+fn match_subpath_pattern<'a>(pattern: &str, specifier: &'a str) -> Option<&'a str> {
+    let (prefix, suffix) = pattern.split_once('*')?;
+
+    specifier
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)
+        .filter(|matched| !matched.is_empty())
+}
+
+/// Collect target specifiers of all conditions and fallbacks.
+fn collect_subpath_targets(target: &Value, targets: &mut Vec<String>) {
+    match target {
+        Value::String(target) => targets.push(target.to_owned()),
+        Value::Array(fallbacks) => {
+            for fallback in fallbacks {
+                collect_subpath_targets(fallback, targets);
+            }
+        }
+        Value::Object(conditions) => {
+            for condition in conditions.values() {
+                collect_subpath_targets(condition, targets);
+            }
+        }
+        _ => {}
     }
 }
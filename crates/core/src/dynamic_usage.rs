@@ -0,0 +1,159 @@
+use swc_common::{SourceFile, Span, Spanned};
+use swc_ecma_ast::{BinaryOp, CallExpr, Callee, Expr, Lit, Module, NewExpr};
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Collects spans of code evaluated at runtime, which may hide requires of packages.
+#[derive(Debug, Default)]
+struct DynamicUsageCollector {
+    spans: Vec<Span>,
+}
+
+impl Visit for DynamicUsageCollector {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee {
+            if is_evaluating_callee(callee) {
+                self.spans.push(call.span);
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+
+    fn visit_new_expr(&mut self, new: &NewExpr) {
+        if is_evaluating_callee(&new.callee) {
+            self.spans.push(new.span);
+        }
+
+        new.visit_children_with(self);
+    }
+}
+
+/// Collects spans of non literal arguments of `import(...)` and `require(...)` calls.
+#[derive(Debug, Default)]
+struct DynamicSpecifiersCollector {
+    spans: Vec<Span>,
+}
+
+impl Visit for DynamicSpecifiersCollector {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Some(argument) = call.args.first().filter(|_| is_import_call(call)) {
+            if argument.spread.is_none() && !is_static_specifier(&argument.expr) {
+                self.spans.push(argument.expr.span());
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+}
+
+/// Collects leading string literals of concatenated arguments of `import(...)` and
+/// `require(...)` calls like `require('date-fns/locale/' + lang)`.
+#[derive(Debug, Default)]
+struct ConcatenatedSpecifiersCollector {
+    prefixes: Vec<String>,
+}
+
+impl Visit for ConcatenatedSpecifiersCollector {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Some(argument) = call.args.first().filter(|_| is_import_call(call)) {
+            if argument.spread.is_none() && !is_static_specifier(&argument.expr) {
+                self.prefixes.extend(get_leading_literal(&argument.expr));
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+}
+
+fn is_import_call(call: &CallExpr) -> bool {
+    match &call.callee {
+        Callee::Import(_) => true,
+        Callee::Expr(callee) => {
+            matches!(&**callee, Expr::Ident(ident) if &*ident.sym == "require")
+        }
+        Callee::Super(_) => false,
+    }
+}
+
+/// Get the leading static string of a concatenation like `'pkg/' + name + '.js'`
+/// or of a template like `` `pkg/${name}` ``.
+fn get_leading_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(string)) => Some(string.value.to_string()),
+        Expr::Bin(bin) if bin.op == BinaryOp::Add => get_leading_literal(&bin.left),
+        Expr::Tpl(tpl) => tpl.quasis.first().map(|quasi| quasi.raw.to_string()),
+        Expr::Paren(paren) => get_leading_literal(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Check if a prefix contains a whole package name like `pkg/` or `@scope/pkg/`,
+/// so `pkg-` + name isn't attributed to `pkg-`.
+fn is_package_prefix(prefix: &str) -> bool {
+    match prefix.strip_prefix('@') {
+        Some(scoped) => scoped.splitn(3, '/').count() == 3,
+        None => prefix.contains('/'),
+    }
+}
+
+fn is_static_specifier(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(Lit::Str(_)) => true,
+        Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+        _ => false,
+    }
+}
+
+/// Check if a callee is `eval` or `Function`, which evaluate strings as code.
+fn is_evaluating_callee(callee: &Expr) -> bool {
+    match callee {
+        Expr::Ident(ident) => &*ident.sym == "eval" || &*ident.sym == "Function",
+        Expr::Paren(paren) => is_evaluating_callee(&paren.expr),
+        _ => false,
+    }
+}
+
+/// Get source texts of `eval(...)`, `Function(...)` and `new Function(...)` calls of a module.
+pub fn collect_dynamic_usage(module: &Module, source: &SourceFile) -> Vec<String> {
+    let mut collector = DynamicUsageCollector::default();
+    module.visit_with(&mut collector);
+
+    collector
+        .spans
+        .into_iter()
+        .filter_map(|span| get_source_text(source, span))
+        .collect()
+}
+
+/// Get source texts of non literal specifiers of `import(...)` and `require(...)` calls.
+pub fn collect_dynamic_specifiers(module: &Module, source: &SourceFile) -> Vec<String> {
+    let mut collector = DynamicSpecifiersCollector::default();
+    module.visit_with(&mut collector);
+
+    collector
+        .spans
+        .into_iter()
+        .filter_map(|span| get_source_text(source, span))
+        .collect()
+}
+
+/// Get leading literals of concatenated specifiers of `import(...)` and `require(...)` calls
+/// which name a package like `date-fns/locale/` of `require('date-fns/locale/' + lang)`.
+pub fn collect_concatenated_specifiers(module: &Module) -> Vec<String> {
+    let mut collector = ConcatenatedSpecifiersCollector::default();
+    module.visit_with(&mut collector);
+
+    collector
+        .prefixes
+        .into_iter()
+        .filter(|prefix| is_package_prefix(prefix))
+        .collect()
+}
+
+/// Get a source text of a span.
+pub fn get_source_text(source: &SourceFile, span: Span) -> Option<String> {
+    let start = span.lo.0.checked_sub(source.start_pos.0)? as usize;
+    let end = span.hi.0.checked_sub(source.start_pos.0)? as usize;
+
+    source.src.get(start..end).map(|text| text.to_owned())
+}
@@ -0,0 +1,64 @@
+use regex::Regex;
+use std::path::Path;
+
+/// Types of `<script>` elements containing JavaScript, an element without a type is a script.
+const SCRIPT_TYPES: [&str; 4] = [
+    "module",
+    "text/javascript",
+    "application/javascript",
+    "text/ecmascript",
+];
+
+/// A JavaScript source of an inline `<script>` element.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EmbeddedScript {
+    pub source: String,
+    pub is_es_module: bool,
+}
+
+/// Check if a file is a template of the extensions, `html.twig` matches `index.html.twig`.
+pub fn is_embedded_script_file(path: &Path, extensions: &[String]) -> bool {
+    let file_name = match path.file_name().and_then(|file_name| file_name.to_str()) {
+        Some(file_name) => file_name,
+        None => return false,
+    };
+
+    extensions.iter().any(|extension| {
+        let extension = extension.trim_start_matches('.');
+        file_name
+            .strip_suffix(extension)
+            .map_or(false, |name| name.len() > 1 && name.ends_with('.'))
+    })
+}
+
+/// Get sources of inline `<script>` elements containing JavaScript.
+pub fn collect_embedded_scripts(content: &str) -> Vec<EmbeddedScript> {
+    let script_pattern = Regex::new(r"(?is)<script\b([^>]*)>(.*?)</script\s*>").unwrap();
+    let type_pattern = Regex::new(r#"(?i)\btype\s*=\s*["']?([^"'\s>]+)"#).unwrap();
+
+    script_pattern
+        .captures_iter(content)
+        .filter_map(|captures| {
+            let attributes = captures.get(1)?.as_str();
+            let source = captures.get(2)?.as_str();
+
+            let script_type = type_pattern
+                .captures(attributes)
+                .and_then(|captures| captures.get(1))
+                .map(|script_type| script_type.as_str().to_ascii_lowercase());
+
+            let is_script = script_type
+                .as_deref()
+                .map_or(true, |script_type| SCRIPT_TYPES.contains(&script_type));
+
+            if !is_script || source.trim().is_empty() {
+                return None;
+            }
+
+            Some(EmbeddedScript {
+                source: source.to_owned(),
+                is_es_module: script_type.as_deref() == Some("module"),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,79 @@
+use std::error::Error as StdError;
+use std::io;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors of the dependencies checker.
+#[derive(Debug, Error)]
+pub enum DepcheckError {
+    /// Failed to read a file or a directory.
+    #[error("Failed to read {path:?}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    /// Failed to parse a manifest like `package.json`, a lockfile or a baseline.
+    ///
+    /// The source is the error of the deserializer like `serde_json::Error`, or a message
+    /// when the manifest is read but isn't supported.
+    #[error("Failed to parse {path:?}: {source}")]
+    ParseManifest {
+        path: PathBuf,
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+    /// Failed to walk a directory.
+    #[error("Failed to walk {path:?}")]
+    Walk {
+        path: PathBuf,
+        #[source]
+        source: ignore::Error,
+    },
+    /// Failed to watch a directory for changes.
+    #[error("Failed to watch {path:?}")]
+    Watch {
+        path: PathBuf,
+        #[source]
+        source: notify::Error,
+    },
+    /// Malformed ignore pattern.
+    #[error("Malformed ignore pattern: {pattern}")]
+    Pattern {
+        pattern: String,
+        #[source]
+        source: ignore::Error,
+    },
+    /// Files failed to parse while parse errors fail the check.
+    #[error("Failed to parse {files:?} of {directory:?}")]
+    ParseSource {
+        directory: PathBuf,
+        files: Vec<String>,
+    },
+    /// A special of this name doesn't exist.
+    #[error("Unknown special {name}")]
+    UnknownSpecial { name: String },
+    /// The check is cancelled by the cancellation flag.
+    #[error("The check of {directory:?} is cancelled")]
+    Cancelled { directory: PathBuf },
+}
+
+impl DepcheckError {
+    pub(crate) fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        DepcheckError::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub(crate) fn parse_manifest(
+        path: impl Into<PathBuf>,
+        source: impl Into<Box<dyn StdError + Send + Sync>>,
+    ) -> Self {
+        DepcheckError::ParseManifest {
+            path: path.into(),
+            source: source.into(),
+        }
+    }
+}
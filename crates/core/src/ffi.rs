@@ -0,0 +1,93 @@
+//! A C ABI to check directories from non-Rust tools.
+//!
+//! `depcheck_check` writes a JSON report allocated by Rust, it must be released with
+//! `depcheck_free` and never with `free` of the C allocator.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::panic;
+use std::path::PathBuf;
+use std::ptr;
+
+use crate::checker::Checker;
+use crate::config::Config;
+
+/// The check succeeded and the report is written to `json_out`.
+pub const DEPCHECK_OK: c_int = 0;
+
+/// `dir` or `json_out` is null or `dir` isn't valid UTF-8.
+pub const DEPCHECK_INVALID_ARGUMENT: c_int = 1;
+
+/// The check failed, like when `dir` has no `package.json`.
+pub const DEPCHECK_CHECK_FAILED: c_int = 2;
+
+/// The check panicked or its report couldn't be serialized.
+pub const DEPCHECK_INTERNAL_ERROR: c_int = 3;
+
+/// Check dependencies of a directory with the default config and write its JSON `Report`.
+///
+/// On success `*json_out` points to a NUL terminated report owned by the caller, otherwise
+/// it's set to null unless `json_out` itself is null. Returns one of the `DEPCHECK_*` codes.
+///
+/// # Safety
+///
+/// `dir` must be null or point to a NUL terminated string, `json_out` must be null or valid
+/// for writes. The report must be released exactly once with `depcheck_free`.
+#[no_mangle]
+pub unsafe extern "C" fn depcheck_check(dir: *const c_char, json_out: *mut *mut c_char) -> c_int {
+    if json_out.is_null() {
+        return DEPCHECK_INVALID_ARGUMENT;
+    }
+
+    *json_out = ptr::null_mut();
+
+    if dir.is_null() {
+        return DEPCHECK_INVALID_ARGUMENT;
+    }
+
+    let directory = match CStr::from_ptr(dir).to_str() {
+        Ok(directory) => PathBuf::from(directory),
+        Err(_) => return DEPCHECK_INVALID_ARGUMENT,
+    };
+
+    // panics must not unwind across the C boundary
+    let report = panic::catch_unwind(|| {
+        Checker::new(Config::new(directory))
+            .check_package()
+            .map(|result| serde_json::to_string(&result.report()))
+    });
+
+    let json = match report {
+        Ok(Ok(Ok(json))) => json,
+        Ok(Err(error)) => {
+            log::error!("failed to check dependencies: {}", error);
+            return DEPCHECK_CHECK_FAILED;
+        }
+        Ok(Ok(Err(error))) => {
+            log::error!("failed to serialize the report: {:#?}", error);
+            return DEPCHECK_INTERNAL_ERROR;
+        }
+        Err(_) => return DEPCHECK_INTERNAL_ERROR,
+    };
+
+    // JSON strings escape control characters, so the report has no interior NUL
+    match CString::new(json) {
+        Ok(json) => {
+            *json_out = json.into_raw();
+            DEPCHECK_OK
+        }
+        Err(_) => DEPCHECK_INTERNAL_ERROR,
+    }
+}
+
+/// Release a report written by `depcheck_check`, null is ignored.
+///
+/// # Safety
+///
+/// `json` must be null or a report of `depcheck_check` which isn't released yet.
+#[no_mangle]
+pub unsafe extern "C" fn depcheck_free(json: *mut c_char) {
+    if !json.is_null() {
+        drop(CString::from_raw(json));
+    }
+}
@@ -0,0 +1,84 @@
+use swc_ecma_ast::{ArrayLit, CallExpr, Callee, Expr, Lit, MemberProp, Module};
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Functions of AMD loaders like RequireJS taking a list of dependencies.
+const AMD_FUNCTIONS: [&str; 3] = ["define", "require", "requirejs"];
+
+/// Dependencies of AMD modules provided by the loader rather than by packages.
+const AMD_PSEUDO_DEPENDENCIES: [&str; 3] = ["require", "exports", "module"];
+
+/// Collects specifiers of `System.import('pkg')` calls and AMD dependency lists like
+/// `define(['dep-a', 'dep-b'], factory)`.
+#[derive(Debug, Default)]
+struct LegacyModulesCollector {
+    specifiers: Vec<String>,
+}
+
+impl Visit for LegacyModulesCollector {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee {
+            match &**callee {
+                Expr::Ident(ident) if AMD_FUNCTIONS.contains(&&*ident.sym) => {
+                    self.specifiers.extend(
+                        get_amd_dependency_list(call)
+                            .into_iter()
+                            .flat_map(get_amd_dependencies),
+                    );
+                }
+                Expr::Member(member) if is_system_import(&member.obj, &member.prop) => {
+                    if let Some(Expr::Lit(Lit::Str(specifier))) =
+                        call.args.first().map(|argument| &*argument.expr)
+                    {
+                        self.specifiers.push(specifier.value.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+}
+
+/// Check if a member expression is `System.import` of SystemJS.
+fn is_system_import(obj: &Expr, prop: &MemberProp) -> bool {
+    matches!(obj, Expr::Ident(ident) if &*ident.sym == "System")
+        && matches!(prop, MemberProp::Ident(prop) if &*prop.sym == "import")
+}
+
+/// Get a dependency list of an AMD call, named modules like `define('name', [...], factory)`
+/// list dependencies second.
+fn get_amd_dependency_list(call: &CallExpr) -> Option<&ArrayLit> {
+    call.args
+        .iter()
+        .take(2)
+        .find_map(|argument| match &*argument.expr {
+            Expr::Array(array) => Some(array),
+            _ => None,
+        })
+}
+
+/// Get string specifiers of an AMD dependency list.
+///
+/// Resources of loader plugins like `text!./template.html` aren't modules, so they're skipped.
+fn get_amd_dependencies(array: &ArrayLit) -> Vec<String> {
+    array
+        .elems
+        .iter()
+        .flatten()
+        .filter_map(|element| match &*element.expr {
+            Expr::Lit(Lit::Str(specifier)) => Some(specifier.value.to_string()),
+            _ => None,
+        })
+        .filter(|specifier| !AMD_PSEUDO_DEPENDENCIES.contains(&specifier.as_str()))
+        .filter(|specifier| !specifier.contains('!'))
+        .collect()
+}
+
+/// Get specifiers of legacy module systems, SystemJS `System.import('pkg')` calls and
+/// dependency lists of AMD `define` and `require` calls.
+pub fn collect_legacy_module_specifiers(module: &Module) -> Vec<String> {
+    let mut collector = LegacyModulesCollector::default();
+    module.visit_with(&mut collector);
+    collector.specifiers
+}
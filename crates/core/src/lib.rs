@@ -1,7 +1,24 @@
+pub mod baseline;
 pub mod checker;
 pub mod checker_result;
 pub mod config;
+pub(crate) mod create_require;
+pub(crate) mod css;
+pub(crate) mod dead_imports;
 pub(crate) mod dependency;
+pub(crate) mod dynamic_usage;
+pub(crate) mod embedded_script;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub(crate) mod legacy_modules;
+pub(crate) mod loader_hooks;
+pub(crate) mod lockfile;
+pub(crate) mod manifest_fields;
+pub(crate) mod module_augmentation;
 pub(crate) mod package;
-pub(crate) mod parser;
+pub mod parser;
+pub(crate) mod special;
+pub(crate) mod tsconfig;
+pub(crate) mod use_directive;
 pub(crate) mod util;
@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+use swc_atoms::JsWord;
+use swc_ecma_ast::{
+    CallExpr, Callee, Expr, ImportDecl, ImportSpecifier, Lit, MemberProp, Module, ModuleExportName,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Specifiers of the core module providing `register` of loader hooks.
+const MODULE_SPECIFIERS: [&str; 2] = ["module", "node:module"];
+
+/// Collects local names of the `module` core module and of its `register` function.
+#[derive(Debug, Default)]
+struct ModuleImportsCollector {
+    modules: HashSet<JsWord>,
+    registers: HashSet<JsWord>,
+}
+
+impl Visit for ModuleImportsCollector {
+    fn visit_import_decl(&mut self, import: &ImportDecl) {
+        if !MODULE_SPECIFIERS.contains(&&*import.src.value) {
+            return;
+        }
+
+        for specifier in &import.specifiers {
+            match specifier {
+                ImportSpecifier::Default(default) => {
+                    self.modules.insert(default.local.sym.clone());
+                }
+                ImportSpecifier::Namespace(namespace) => {
+                    self.modules.insert(namespace.local.sym.clone());
+                }
+                ImportSpecifier::Named(named) => {
+                    let imported = match &named.imported {
+                        Some(ModuleExportName::Ident(imported)) => &imported.sym,
+                        Some(ModuleExportName::Str(imported)) => &imported.value,
+                        None => &named.local.sym,
+                    };
+
+                    if &**imported == "register" {
+                        self.registers.insert(named.local.sym.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Collects literal specifiers of `module.register(...)` and `register(...)` calls.
+#[derive(Debug)]
+struct RegisteredLoadersCollector {
+    modules: HashSet<JsWord>,
+    registers: HashSet<JsWord>,
+    specifiers: Vec<String>,
+}
+
+impl RegisteredLoadersCollector {
+    fn is_register_callee(&self, callee: &Expr) -> bool {
+        match callee {
+            Expr::Ident(ident) => self.registers.contains(&ident.sym),
+            Expr::Member(member) => {
+                let is_module = matches!(
+                    &*member.obj,
+                    Expr::Ident(object) if self.modules.contains(&object.sym)
+                );
+
+                is_module
+                    && matches!(&member.prop, MemberProp::Ident(prop) if &*prop.sym == "register")
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Visit for RegisteredLoadersCollector {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        let is_register = match &call.callee {
+            Callee::Expr(callee) => self.is_register_callee(callee),
+            _ => false,
+        };
+
+        if let Some(argument) = call.args.first().filter(|_| is_register) {
+            if let Expr::Lit(Lit::Str(specifier)) = &*argument.expr {
+                self.specifiers.push(specifier.value.to_string());
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+}
+
+/// Get specifiers of loader hooks registered by `register` of the `module` core module
+/// like `module.register('ts-node/esm', pathToFileURL('./'))`.
+///
+/// The CommonJS `module` object is matched by its name as it isn't imported.
+pub fn collect_registered_loaders(module: &Module) -> Vec<String> {
+    let mut imports_collector = ModuleImportsCollector::default();
+    module.visit_with(&mut imports_collector);
+
+    let mut modules = imports_collector.modules;
+    modules.insert(JsWord::from("module"));
+
+    let mut collector = RegisteredLoadersCollector {
+        modules,
+        registers: imports_collector.registers,
+        specifiers: Vec::new(),
+    };
+    module.visit_with(&mut collector);
+
+    collector.specifiers
+}
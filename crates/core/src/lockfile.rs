@@ -0,0 +1,77 @@
+use crate::error::DepcheckError;
+use crate::package::{DepsSet, Package};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Lockfiles listing direct dependencies of the root package.
+const SUPPORTED_LOCKFILES: [&str; 2] = ["package-lock.json", "npm-shrinkwrap.json"];
+
+/// Direct dependencies of the root package recorded by a lockfile.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedDependencies {
+    #[serde(default)]
+    pub dependencies: DepsSet,
+    #[serde(default)]
+    pub dev_dependencies: DepsSet,
+    #[serde(default)]
+    pub peer_dependencies: DepsSet,
+    #[serde(default)]
+    pub optional_dependencies: DepsSet,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackageLock {
+    #[serde(default)]
+    lockfile_version: u64,
+    /// Packages keyed by their paths, the root package has an empty path.
+    #[serde(default)]
+    packages: BTreeMap<String, LockedDependencies>,
+}
+
+impl LockedDependencies {
+    /// Deserializes direct dependencies from a `package-lock.json` of version 2 or later.
+    pub fn from_path(path: &Path) -> Result<Self, DepcheckError> {
+        let file_name = path.file_name().and_then(|file_name| file_name.to_str());
+
+        if !file_name.map_or(false, |file_name| SUPPORTED_LOCKFILES.contains(&file_name)) {
+            return Err(DepcheckError::parse_manifest(
+                path,
+                "unsupported lockfile, only package-lock.json is supported",
+            ));
+        }
+
+        let content = fs::read(path).map_err(|error| DepcheckError::io(path, error))?;
+        let mut package_lock = serde_json::from_slice::<PackageLock>(&content)
+            .map_err(|error| DepcheckError::parse_manifest(path, error))?;
+
+        if package_lock.lockfile_version < 2 {
+            return Err(DepcheckError::parse_manifest(
+                path,
+                format!(
+                    "lockfile of version {} has no direct dependencies, version 2 is required",
+                    package_lock.lockfile_version
+                ),
+            ));
+        }
+
+        package_lock
+            .packages
+            .remove("")
+            .ok_or_else(|| DepcheckError::parse_manifest(path, "lockfile has no root package"))
+    }
+
+    /// Replace dependencies declared by a package json with the locked ones.
+    pub fn apply(self, package: Package) -> Package {
+        Package {
+            dependencies: self.dependencies,
+            dev_dependencies: self.dev_dependencies,
+            peer_dependencies: self.peer_dependencies,
+            optional_dependencies: self.optional_dependencies,
+            ..package
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use serde_json::Value;
+
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+/// Get package names referenced by values of `package.json` fields.
+///
+/// A field is a dot separated path like `config.commitizen.path`, arrays on the path are
+/// traversed element by element. A value is a package name, a list of package names or
+/// a list of `[name, options]` tuples like `release.plugins` of semantic-release.
+pub fn collect_manifest_field_packages(manifest: &Value, fields: &[String]) -> Vec<String> {
+    fields
+        .iter()
+        .flat_map(|field| {
+            let mut values = Vec::new();
+            collect_field_values(manifest, &field.split('.').collect::<Vec<_>>(), &mut values);
+            values
+        })
+        .flat_map(get_value_specifiers)
+        .filter_map(get_package_name)
+        .collect()
+}
+
+fn collect_field_values<'a>(value: &'a Value, path: &[&str], values: &mut Vec<&'a Value>) {
+    let (key, path) = match (value, path.split_first()) {
+        (_, None) => {
+            values.push(value);
+            return;
+        }
+        (Value::Array(array), Some(_)) => {
+            for element in array {
+                collect_field_values(element, path, values);
+            }
+            return;
+        }
+        (_, Some(split)) => split,
+    };
+
+    if let Some(value) = value.get(*key) {
+        collect_field_values(value, path, values);
+    }
+}
+
+fn get_value_specifiers(value: &Value) -> Vec<&str> {
+    match value {
+        Value::String(specifier) => vec![specifier.as_str()],
+        Value::Array(elements) => elements
+            .iter()
+            .filter_map(|element| match element {
+                Value::Array(tuple) => tuple.first().and_then(Value::as_str),
+                element => element.as_str(),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Get a package of a specifier like `cz-conventional-changelog`
+/// or `./node_modules/cz-conventional-changelog`.
+fn get_package_name(specifier: &str) -> Option<String> {
+    let specifier = match specifier.rsplit_once("node_modules/") {
+        Some((_, specifier)) => specifier,
+        None => specifier,
+    };
+
+    if !is_external_specifier(specifier) {
+        return None;
+    }
+
+    extract_package_name(specifier)
+}
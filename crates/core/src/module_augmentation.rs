@@ -0,0 +1,34 @@
+use swc_ecma_ast::{Module, TsModuleDecl, TsModuleName};
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Collects names of modules declared like `declare module 'pkg' { ... }`.
+#[derive(Debug, Default)]
+struct AugmentedModulesCollector {
+    specifiers: Vec<String>,
+}
+
+impl Visit for AugmentedModulesCollector {
+    fn visit_ts_module_decl(&mut self, declaration: &TsModuleDecl) {
+        if let TsModuleName::Str(specifier) = &declaration.id {
+            self.specifiers.push(specifier.value.to_string());
+        }
+
+        declaration.visit_children_with(self);
+    }
+}
+
+/// Get specifiers of modules augmented by `declare module 'pkg'` statements, the checker
+/// collects them only of declaration files.
+///
+/// Wildcard declarations like `declare module '*.svg'` type assets rather than packages,
+/// so they're skipped.
+pub fn collect_augmented_modules(module: &Module) -> Vec<String> {
+    let mut collector = AugmentedModulesCollector::default();
+    module.visit_with(&mut collector);
+
+    collector
+        .specifiers
+        .into_iter()
+        .filter(|specifier| !specifier.contains('*'))
+        .collect()
+}
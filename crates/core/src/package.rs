@@ -1,5 +1,8 @@
-use serde::{Deserialize, Serialize};
+use crate::error::DepcheckError;
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::BTreeMap;
+use std::fmt;
 use std::fs;
 use std::io::Read;
 use std::path::Path;
@@ -18,6 +21,9 @@ pub struct Package {
     /// The package version.
     #[serde(default)]
     pub version: String,
+    /// Whether the package is never published, like an application.
+    #[serde(default)]
+    pub private: bool,
     /// The optional list of dependencies.
     #[serde(default)]
     pub dependencies: DepsSet,
@@ -27,6 +33,9 @@ pub struct Package {
     /// The optional list of peer dependencies.
     #[serde(default)]
     pub peer_dependencies: DepsSet,
+    /// The optional metadata of peer dependencies.
+    #[serde(default)]
+    pub peer_dependencies_meta: BTreeMap<String, PeerDependencyMeta>,
     /// The optional list of bundled dependencies.
     #[serde(default)]
     pub bundled_dependencies: DepsSet,
@@ -36,6 +45,46 @@ pub struct Package {
     /// The optional set of binary definitions.
     #[serde(default)]
     pub bin: Option<BinSet>,
+    /// The optional main entry file.
+    #[serde(default)]
+    pub main: Option<String>,
+    /// The optional ES module entry file.
+    #[serde(default)]
+    pub module: Option<String>,
+    /// The optional type declarations entry file.
+    #[serde(default)]
+    pub types: Option<String>,
+    /// The optional exports map.
+    #[serde(default)]
+    pub exports: Option<serde_json::Value>,
+    /// The optional subpath imports map of `#` specifiers.
+    #[serde(default)]
+    pub imports: Option<serde_json::Value>,
+    /// The module format of `.js` files.
+    #[serde(default, rename = "type")]
+    pub package_type: PackageType,
+    /// Names of dependencies of all sections in the order they're declared.
+    #[serde(skip)]
+    declaration_order: Vec<String>,
+}
+
+/// The metadata of a peer dependency declared by the `peerDependenciesMeta` field.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PeerDependencyMeta {
+    /// Whether the peer dependency may be absent.
+    #[serde(default)]
+    pub optional: bool,
+}
+
+/// The module format of `.js` files declared by the `type` field.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageType {
+    /// `.js` files are CommonJS scripts, which is the default.
+    #[default]
+    CommonJs,
+    /// `.js` files are ES modules.
+    Module,
 }
 
 impl Package {
@@ -45,19 +94,72 @@ impl Package {
     }
 
     /// Deserializes a `Package` from a file path.
-    pub fn from_path<T: AsRef<Path>>(path: T) -> eyre::Result<Self> {
-        let content = fs::read(path.as_ref())?;
+    pub fn from_path<T: AsRef<Path>>(path: T) -> Result<Self, DepcheckError> {
+        let path = path.as_ref();
+        let content = fs::read(path).map_err(|error| DepcheckError::io(path, error))?;
         Self::from_slice(content.as_slice())
+            .map_err(|error| DepcheckError::parse_manifest(path, error))
+    }
+
+    /// Deserializes a `Package` from a file path keeping the order its dependencies are
+    /// declared in, which takes a second pass over the manifest.
+    pub fn from_path_with_declaration_order<T: AsRef<Path>>(
+        path: T,
+    ) -> Result<Self, DepcheckError> {
+        let path = path.as_ref();
+        let content = fs::read(path).map_err(|error| DepcheckError::io(path, error))?;
+        let mut package = Self::from_slice(content.as_slice())
+            .map_err(|error| DepcheckError::parse_manifest(path, error))?;
+
+        package.declaration_order = serde_json::from_slice::<DeclarationOrder>(&content)
+            .map_err(|error| DepcheckError::parse_manifest(path, error))?
+            .into_names();
+
+        Ok(package)
     }
 
     /// Deserializes a `Package` from an IO stream.
-    pub fn from_reader<R: Read>(r: R) -> eyre::Result<Self> {
-        Ok(serde_json::from_reader(r)?)
+    pub fn from_reader<R: Read>(mut r: R) -> serde_json::Result<Self> {
+        let mut content = Vec::new();
+        r.read_to_end(&mut content).map_err(serde_json::Error::io)?;
+        Self::from_slice(content.as_slice())
     }
 
     /// Deserializes a `Package` from bytes.
-    pub fn from_slice(v: &[u8]) -> eyre::Result<Self> {
-        Ok(serde_json::from_slice(v)?)
+    pub fn from_slice(v: &[u8]) -> serde_json::Result<Self> {
+        serde_json::from_slice(v)
+    }
+
+    /// Merge dependency sections of an overlay manifest, like one merged at build time.
+    ///
+    /// Sections are unioned and a version of the overlay overrides a version of the same
+    /// dependency of the section, other fields of the package are kept.
+    pub fn merge(mut self, overlay: Package) -> Self {
+        self.dependencies.extend(overlay.dependencies);
+        self.dev_dependencies.extend(overlay.dev_dependencies);
+        self.peer_dependencies.extend(overlay.peer_dependencies);
+        self.peer_dependencies_meta
+            .extend(overlay.peer_dependencies_meta);
+        self.bundled_dependencies
+            .extend(overlay.bundled_dependencies);
+        self.optional_dependencies
+            .extend(overlay.optional_dependencies);
+
+        for name in overlay.declaration_order {
+            if !self.declaration_order.contains(&name) {
+                self.declaration_order.push(name);
+            }
+        }
+
+        self
+    }
+
+    /// Get names of dependencies of all sections in the order they're declared by the manifest,
+    /// `dependencies` go first followed by `devDependencies`, peer and optional ones.
+    ///
+    /// The order is empty unless the package is loaded by `from_path_with_declaration_order`.
+    pub fn get_declaration_order(&self) -> &[String] {
+        &self.declaration_order
     }
 }
 
@@ -74,6 +176,12 @@ impl Package {
         self.peer_dependencies.contains_key(dependency)
     }
 
+    pub fn is_optional_peer_dependency(&self, dependency: &str) -> bool {
+        self.peer_dependencies_meta
+            .get(dependency)
+            .map_or(false, |meta| meta.optional)
+    }
+
     pub fn is_optional_dependency(&self, dependency: &str) -> bool {
         self.optional_dependencies.contains_key(dependency)
     }
@@ -85,3 +193,70 @@ impl Package {
             || self.is_optional_dependency(dependency)
     }
 }
+
+/// Names of dependencies of sections in the order of the manifest, which the ordered maps of
+/// `DepsSet` don't keep.
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeclarationOrder {
+    #[serde(default)]
+    dependencies: OrderedKeys,
+    #[serde(default)]
+    dev_dependencies: OrderedKeys,
+    #[serde(default)]
+    peer_dependencies: OrderedKeys,
+    #[serde(default)]
+    optional_dependencies: OrderedKeys,
+}
+
+impl DeclarationOrder {
+    fn into_names(self) -> Vec<String> {
+        let mut names = Vec::new();
+
+        for name in [
+            self.dependencies,
+            self.dev_dependencies,
+            self.peer_dependencies,
+            self.optional_dependencies,
+        ]
+        .into_iter()
+        .flat_map(|keys| keys.0)
+        {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        names
+    }
+}
+
+/// Keys of a JSON object in their order, values are skipped.
+#[derive(Default)]
+struct OrderedKeys(Vec<String>);
+
+impl<'de> Deserialize<'de> for OrderedKeys {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KeysVisitor;
+
+        impl<'de> Visitor<'de> for KeysVisitor {
+            type Value = OrderedKeys;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an object of dependencies")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut keys = Vec::new();
+
+                while let Some((key, IgnoredAny)) = map.next_entry::<String, IgnoredAny>()? {
+                    keys.push(key);
+                }
+
+                Ok(OrderedKeys(keys))
+            }
+        }
+
+        deserializer.deserialize_map(KeysVisitor)
+    }
+}
@@ -1,45 +1,121 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use crate::package::PackageType;
 
 use swc_common::comments::SingleThreadedComments;
 use swc_common::errors::{ColorConfig, Handler};
 use swc_common::sync::Lrc;
-use swc_common::SourceMap;
-use swc_ecma_ast::Module;
+use swc_common::{FileName, SourceFile, SourceMap};
+use swc_ecma_ast::{Module, ModuleItem, Program};
 use swc_ecma_parser::lexer::Lexer;
 use swc_ecma_parser::{EsConfig, StringInput, Syntax, TsConfig};
 
+/// Extensions of TypeScript declaration files.
+const DECLARATION_EXTENSIONS: [&str; 3] = [".d.ts", ".d.cts", ".d.mts"];
+
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
-pub struct Parser {}
+pub struct Parser {
+    package_type: PackageType,
+    syntax_features: SyntaxFeatures,
+}
+
+/// Syntax proposals enabled while parsing, all of them are enabled by default.
+///
+/// Top level `await` is always enabled for ES modules.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SyntaxFeatures {
+    /// Decorators like `@observable price = 0` of JavaScript and TypeScript files.
+    pub decorators: bool,
+    /// Decorators placed before `export` like `@decorator export class Foo {}`.
+    pub decorators_before_export: bool,
+    /// JSX of `.js` files, `.jsx` and `.tsx` files always allow it.
+    pub jsx: bool,
+    /// Function bind operator `obj::func`.
+    pub fn_bind: bool,
+    /// Default exports re-exported like `export v from "mod"`.
+    pub export_default_from: bool,
+    /// Import assertions like `import json from "./foo.json" assert { type: "json" }`.
+    pub import_assertions: bool,
+    /// Private field brand checks like `#field in obj`.
+    pub private_in_object: bool,
+    /// `super` outside of methods.
+    pub allow_super_outside_method: bool,
+    /// `return` outside of functions.
+    pub allow_return_outside_function: bool,
+}
+
+impl Default for SyntaxFeatures {
+    fn default() -> Self {
+        SyntaxFeatures {
+            decorators: true,
+            decorators_before_export: true,
+            jsx: true,
+            fn_bind: true,
+            export_default_from: true,
+            import_assertions: true,
+            private_in_object: true,
+            allow_super_outside_method: true,
+            allow_return_outside_function: true,
+        }
+    }
+}
 
 impl Parser {
+    /// Get a parser treating `.js` and `.jsx` files according to the package `type`.
+    pub fn with_package_type(mut self, package_type: PackageType) -> Self {
+        self.package_type = package_type;
+        self
+    }
+
+    /// Get a parser enabling syntax proposals of features.
+    pub fn with_syntax_features(mut self, syntax_features: SyntaxFeatures) -> Self {
+        self.syntax_features = syntax_features;
+        self
+    }
+
+    /// Check if a file is an ES module rather than a CommonJS script.
+    fn is_es_module(&self, extension: &str) -> bool {
+        match extension {
+            "cjs" | "cts" => false,
+            "js" | "jsx" => self.package_type == PackageType::Module,
+            _ => true,
+        }
+    }
+
     pub fn parse_file(&self, file: &Path) -> Option<(Module, Syntax)> {
-        let extension = file.extension()?.to_str()?;
+        self.parse_file_with_source(file)
+            .map(|(module, syntax, _, _)| (module, syntax))
+    }
 
-        log::debug!("parse file {:#?}", file);
+    /// Get a syntax used to parse a file by its extension, files of other extensions aren't parsed.
+    pub fn syntax_for_path(&self, path: &Path) -> Option<Syntax> {
+        let extension = path.extension()?.to_str()?;
 
         let syntax = match extension {
             "ts" | "tsx" | "cts" | "mts" => Syntax::Typescript(TsConfig {
-                dts: file.ends_with(".d.ts"),
+                dts: is_declaration_file(path),
                 tsx: extension == "tsx",
-                decorators: true,
+                decorators: self.syntax_features.decorators,
                 no_early_errors: true,
             }),
-            "mjs" | "js" | "jsx" | "cjs" => Syntax::Es(EsConfig {
-                jsx: true,
-                fn_bind: true,
-                decorators: true,
-                decorators_before_export: true,
-                export_default_from: true,
-                import_assertions: true,
-                private_in_object: true,
-                allow_super_outside_method: true,
-                allow_return_outside_function: true,
-            }),
+            "mjs" | "js" | "jsx" | "cjs" => self.es_syntax(extension == "jsx"),
             _ => return None,
         };
 
+        Some(syntax)
+    }
+
+    /// Parse a file keeping its source to get texts of spans and its comments.
+    pub fn parse_file_with_source(
+        &self,
+        file: &Path,
+    ) -> Option<(Module, Syntax, Lrc<SourceFile>, SingleThreadedComments)> {
+        let extension = file.extension()?.to_str()?;
+        let syntax = self.syntax_for_path(file)?;
+
+        log::debug!("parse file {:#?}", file);
+
         let cm: Lrc<SourceMap> = Default::default();
-        let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
 
         let fm = cm
             .load_file(file)
@@ -48,28 +124,103 @@ impl Parser {
             })
             .ok()?;
 
-        let comments = SingleThreadedComments::default();
-        let lexer = Lexer::new(
-            syntax.to_owned(),
-            Default::default(),
-            StringInput::from(&*fm),
-            Some(&comments),
-        );
+        let (module, comments) =
+            parse_source_file(&cm, &fm, &syntax, self.is_es_module(extension))?;
 
-        let mut parser = swc_ecma_parser::Parser::new_from(lexer);
+        Some((module, syntax, fm, comments))
+    }
 
-        for error in parser.take_errors() {
-            error.into_diagnostic(&handler).emit();
-        }
+    /// Parse a JavaScript source embedded in a file, like a `<script>` element of a template.
+    pub(crate) fn parse_embedded_source(
+        &self,
+        file: &Path,
+        source: String,
+        is_es_module: bool,
+    ) -> Option<(Module, Syntax, Lrc<SourceFile>, SingleThreadedComments)> {
+        log::debug!("parse embedded source of {:#?}", file);
 
-        let module: Module = parser
-            .parse_module()
-            .map_err(|e| e.into_diagnostic(&handler).emit())
-            .map_err(|error| {
-                log::error!("failed to parser module {:#?}", error);
-            })
-            .ok()?;
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(FileName::Real(PathBuf::from(file)), source);
+        let syntax = self.es_syntax(false);
+
+        let (module, comments) = parse_source_file(&cm, &fm, &syntax, is_es_module)?;
+
+        Some((module, syntax, fm, comments))
+    }
+
+    fn es_syntax(&self, jsx: bool) -> Syntax {
+        let features = self.syntax_features;
 
-        Some((module, syntax.to_owned()))
+        Syntax::Es(EsConfig {
+            jsx: jsx || features.jsx,
+            fn_bind: features.fn_bind,
+            decorators: features.decorators,
+            decorators_before_export: features.decorators_before_export,
+            export_default_from: features.export_default_from,
+            import_assertions: features.import_assertions,
+            private_in_object: features.private_in_object,
+            allow_super_outside_method: features.allow_super_outside_method,
+            allow_return_outside_function: features.allow_return_outside_function,
+        })
     }
 }
+
+/// Check if a file is a declaration file like `index.d.ts`, `index.d.cts` or `index.d.mts`.
+pub(crate) fn is_declaration_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|file_name| file_name.to_str())
+        .map_or(false, |file_name| {
+            DECLARATION_EXTENSIONS
+                .iter()
+                .any(|extension| file_name.ends_with(extension))
+        })
+}
+
+fn parse_source_file(
+    cm: &Lrc<SourceMap>,
+    fm: &SourceFile,
+    syntax: &Syntax,
+    is_es_module: bool,
+) -> Option<(Module, SingleThreadedComments)> {
+    let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
+
+    let comments = SingleThreadedComments::default();
+    let lexer = Lexer::new(
+        syntax.to_owned(),
+        Default::default(),
+        StringInput::from(fm),
+        Some(&comments),
+    );
+
+    let mut parser = swc_ecma_parser::Parser::new_from(lexer);
+
+    for error in parser.take_errors() {
+        error.into_diagnostic(&handler).emit();
+    }
+
+    // CommonJS scripts are parsed as modules whenever they contain imports or exports
+    let module = if is_es_module {
+        parser.parse_module()
+    } else {
+        parser.parse_program().map(|program| match program {
+            Program::Module(module) => module,
+            Program::Script(script) => Module {
+                span: script.span,
+                body: script.body.into_iter().map(ModuleItem::Stmt).collect(),
+                shebang: script.shebang,
+            },
+        })
+    };
+
+    // the lexer borrows comments until the parser is dropped
+    drop(parser);
+
+    let module = module
+        .map_err(|e| e.into_diagnostic(&handler).emit())
+        .map_err(|error| {
+            log::error!("failed to parser module {:#?}", error);
+        })
+        .ok()?;
+
+    Some((module, comments))
+}
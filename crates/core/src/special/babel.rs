@@ -0,0 +1,98 @@
+use serde_json::Value;
+use std::path::Path;
+
+use crate::special::config_file::{load_config_file, load_package_field};
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::expand_shorthand::expand_shorthand;
+use crate::util::extract_package_name::extract_package_name;
+
+/// File-relative configs like `.babelrc` are walked with `Config::with_scan_hidden`.
+const CONFIG_FILES: [&str; 6] = [
+    "babel.config.js",
+    "babel.config.cjs",
+    "babel.config.mjs",
+    "babel.config.json",
+    ".babelrc",
+    ".babelrc.json",
+];
+
+/// The manifest containing a config in the `babel` field.
+const PACKAGE_FILE: &str = "package.json";
+
+/// The scope of official presets and plugins.
+const BABEL_SCOPE: &str = "@babel";
+
+/// Detects presets and plugins referenced by the babel config, including shorthand names
+/// like `env` for `@babel/preset-env`.
+pub struct Babel;
+
+impl Special for Babel {
+    fn name(&self) -> &'static str {
+        "babel"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| {
+            CONFIG_FILES.contains(&file_name) || file_name == PACKAGE_FILE
+        })
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let config = if get_file_name(path) == Some(PACKAGE_FILE) {
+            load_package_field(path, "babel")
+        } else {
+            load_config_file(path, context.parser)
+        };
+
+        let mut dependencies = config
+            .iter()
+            .flat_map(get_babel_packages)
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect::<Vec<_>>();
+
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
+/// Get candidate packages of presets and plugins of a config, its `env` configs and `overrides`.
+fn get_babel_packages(config: &Value) -> Vec<String> {
+    let nested = config
+        .get("env")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(|env| env.values())
+        .chain(
+            config
+                .get("overrides")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten(),
+        )
+        .flat_map(get_babel_packages);
+
+    get_entry_packages(config, "presets", "preset")
+        .into_iter()
+        .chain(get_entry_packages(config, "plugins", "plugin"))
+        .chain(nested)
+        .collect()
+}
+
+/// Get candidate packages of entries of a list like `["env", ["module:pkg", {}]]`.
+fn get_entry_packages(config: &Value, key: &str, prefix: &str) -> Vec<String> {
+    config
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| match entry {
+            Value::Array(tuple) => tuple.first().and_then(Value::as_str),
+            entry => entry.as_str(),
+        })
+        .flat_map(|name| match name.strip_prefix("module:") {
+            Some(specifier) => extract_package_name(specifier).into_iter().collect(),
+            None => expand_shorthand(name, BABEL_SCOPE, prefix),
+        })
+        .collect()
+}
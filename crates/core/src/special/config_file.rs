@@ -0,0 +1,51 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use crate::parser::Parser;
+use crate::special::js_config::get_exported_config;
+use crate::util::strip_jsonc::strip_jsonc;
+
+/// Load a tool config file as a JSON value based on its extension.
+///
+/// JSON files may contain comments, JavaScript and TypeScript files are statically evaluated.
+/// Files without extensions like `.babelrc` are JSON.
+pub fn load_config_file(path: &Path, parser: &Parser) -> Option<Value> {
+    let extension = match path.extension() {
+        Some(extension) => extension.to_str()?,
+        None => "json",
+    };
+
+    match extension {
+        "json" => read_file(path).and_then(|content| {
+            serde_json::from_str(&strip_jsonc(&content))
+                .map_err(|error| log::error!("failed to parse {:#?}: {:#?}", path, error))
+                .ok()
+        }),
+        "yml" | "yaml" => read_file(path).and_then(|content| {
+            serde_yaml::from_str(&content)
+                .map_err(|error| log::error!("failed to parse {:#?}: {:#?}", path, error))
+                .ok()
+        }),
+        _ => parser
+            .parse_file(path)
+            .and_then(|(module, _)| get_exported_config(&module)),
+    }
+}
+
+/// Load a tool config of a field of a `package.json` like `jest`.
+pub fn load_package_field(path: &Path, field: &str) -> Option<Value> {
+    let content = read_file(path)?;
+
+    serde_json::from_str::<Value>(&content)
+        .map_err(|error| log::error!("failed to parse {:#?}: {:#?}", path, error))
+        .ok()?
+        .get(field)
+        .cloned()
+}
+
+fn read_file(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .map_err(|error| log::error!("failed to read {:#?}: {:#?}", path, error))
+        .ok()
+}
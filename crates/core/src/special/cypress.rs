@@ -0,0 +1,121 @@
+use serde_json::Value;
+use std::fs;
+use std::iter;
+use std::path::{Path, PathBuf};
+
+use crate::special::config_file::load_config_file;
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+const CONFIG_FILES: [&str; 5] = [
+    "cypress.config.js",
+    "cypress.config.cjs",
+    "cypress.config.mjs",
+    "cypress.config.ts",
+    "cypress.json",
+];
+
+/// The package reading `cypress.config.js`.
+const CLI_PACKAGE: &str = "cypress";
+
+/// Directories of support files and plugins which are checked even when they're ignored.
+const SUPPORT_DIRECTORIES: [&str; 2] = ["cypress/support", "cypress/plugins"];
+
+/// Options of support files and plugins, either top level or of testing types.
+const FILE_OPTIONS: [&str; 2] = ["supportFile", "pluginsFile"];
+
+const TESTING_TYPES: [&str; 2] = ["e2e", "component"];
+
+/// Detects reporters referenced by the cypress config and checks its support files.
+pub struct Cypress;
+
+impl Special for Cypress {
+    fn name(&self) -> &'static str {
+        "cypress"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| CONFIG_FILES.contains(&file_name))
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let config = match load_config_file(path, context.parser) {
+            Some(config) => config,
+            None => return Vec::new(),
+        };
+
+        let reporter = config.get("reporter").and_then(Value::as_str);
+
+        // `cypress-multi-reporters` lists reporters as a comma separated string
+        let enabled_reporters = config
+            .get("reporterOptions")
+            .and_then(|options| options.get("reporterEnabled"))
+            .and_then(Value::as_str)
+            .into_iter()
+            .flat_map(|reporters| reporters.split(','))
+            .map(str::trim);
+
+        let reporters = reporter
+            .into_iter()
+            .chain(enabled_reporters)
+            .filter(|reporter| is_external_specifier(reporter))
+            .filter_map(extract_package_name);
+
+        let mut dependencies = iter::once(CLI_PACKAGE.to_owned())
+            .chain(reporters)
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect::<Vec<_>>();
+
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+
+    fn entry_files(&self, path: &Path, context: &SpecialContext) -> Vec<PathBuf> {
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut entry_files = load_config_file(path, context.parser)
+            .map(|config| {
+                let testing_types = TESTING_TYPES
+                    .iter()
+                    .filter_map(|testing_type| config.get(*testing_type));
+
+                iter::once(&config)
+                    .chain(testing_types)
+                    .flat_map(|options| FILE_OPTIONS.iter().filter_map(|key| options.get(*key)))
+                    .filter_map(Value::as_str)
+                    .map(|file| directory.join(file))
+                    .filter(|file| file.is_file())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        for support_directory in SUPPORT_DIRECTORIES {
+            let support_directory = directory.join(support_directory);
+            collect_source_files(&support_directory, context, &mut entry_files);
+        }
+
+        entry_files.sort();
+        entry_files.dedup();
+        entry_files
+    }
+}
+
+/// Collect files parsed as JavaScript or TypeScript of a directory recursively.
+fn collect_source_files(directory: &Path, context: &SpecialContext, files: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        if path.is_dir() {
+            collect_source_files(&path, context, files);
+        } else if context.parser.syntax_for_path(&path).is_some() {
+            files.push(path);
+        }
+    }
+}
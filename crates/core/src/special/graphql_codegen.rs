@@ -0,0 +1,106 @@
+use serde_json::Value;
+use std::path::Path;
+
+use crate::special::config_file::load_config_file;
+use crate::special::{get_file_name, Special, SpecialContext};
+
+const CONFIG_FILES: [&str; 5] = [
+    "codegen.yml",
+    "codegen.yaml",
+    "codegen.json",
+    "codegen.ts",
+    "codegen.js",
+];
+
+/// The package reading `codegen.yml`.
+const CLI_PACKAGE: &str = "@graphql-codegen/cli";
+
+/// Detects graphql-codegen plugins and presets referenced by `codegen.yml`.
+pub struct GraphqlCodegen;
+
+impl Special for GraphqlCodegen {
+    fn name(&self) -> &'static str {
+        "graphql-codegen"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| CONFIG_FILES.contains(&file_name))
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let config = match load_config_file(path, context.parser) {
+            Some(config) => config,
+            None => return Vec::new(),
+        };
+
+        let outputs = config
+            .get("generates")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flat_map(|generates| generates.values());
+
+        let dependencies = outputs.flat_map(|output| {
+            let plugins = output
+                .get("plugins")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .flat_map(get_plugin_names)
+                .flat_map(|plugin| get_plugin_packages(&plugin));
+
+            let preset = output
+                .get("preset")
+                .and_then(Value::as_str)
+                .into_iter()
+                .flat_map(get_preset_packages);
+
+            plugins.chain(preset).collect::<Vec<_>>()
+        });
+
+        dependencies
+            .chain(Some(String::from(CLI_PACKAGE)))
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect()
+    }
+}
+
+/// Get plugin names of a plugin entry, which is a name or a map of a name to its config.
+fn get_plugin_names(plugin: &Value) -> Vec<String> {
+    match plugin {
+        Value::String(name) => vec![name.to_owned()],
+        Value::Object(plugins) => plugins.keys().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Get packages a plugin name can be resolved to by the codegen naming convention.
+fn get_plugin_packages(name: &str) -> Vec<String> {
+    if is_local(name) {
+        return Vec::new();
+    }
+
+    vec![
+        format!("@graphql-codegen/{name}"),
+        format!("@graphql-codegen/{name}-plugin"),
+        format!("graphql-codegen-{name}"),
+        format!("graphql-codegen-{name}-plugin"),
+        name.to_owned(),
+    ]
+}
+
+/// Get packages a preset name can be resolved to by the codegen naming convention.
+fn get_preset_packages(name: &str) -> Vec<String> {
+    if is_local(name) {
+        return Vec::new();
+    }
+
+    vec![
+        format!("@graphql-codegen/{name}"),
+        format!("@graphql-codegen/{name}-preset"),
+        name.to_owned(),
+    ]
+}
+
+fn is_local(name: &str) -> bool {
+    name.starts_with('.') || name.starts_with('/')
+}
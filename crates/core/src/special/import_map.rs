@@ -0,0 +1,102 @@
+use regex::Regex;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+use crate::util::strip_jsonc::strip_jsonc;
+
+const IMPORT_MAP_FILES: [&str; 2] = ["importmap.json", "import-map.json"];
+
+const HTML_EXTENSIONS: [&str; 2] = ["html", "htm"];
+
+/// Detects packages mapped by bare specifiers in import maps.
+///
+/// Import maps are read from `importmap.json` files and from inline
+/// `<script type="importmap">` elements of HTML files.
+pub struct ImportMap;
+
+impl Special for ImportMap {
+    fn name(&self) -> &'static str {
+        "import-map"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        let is_import_map_file =
+            get_file_name(path).map_or(false, |file_name| IMPORT_MAP_FILES.contains(&file_name));
+
+        is_import_map_file || is_html_file(path)
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) => {
+                log::error!("failed to read {:#?}: {:#?}", path, error);
+                return Vec::new();
+            }
+        };
+
+        let import_maps = if is_html_file(path) {
+            get_inline_import_maps(&content)
+        } else {
+            vec![content]
+        };
+
+        import_maps
+            .iter()
+            .filter_map(|import_map| {
+                serde_json::from_str::<Value>(&strip_jsonc(import_map))
+                    .map_err(|error| log::error!("failed to parse {:#?}: {:#?}", path, error))
+                    .ok()
+            })
+            .flat_map(|import_map| get_mapped_specifiers(&import_map))
+            .filter(|specifier| is_bare_specifier(specifier))
+            .filter_map(|specifier| extract_package_name(&specifier))
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect()
+    }
+}
+
+fn is_html_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .map_or(false, |extension| HTML_EXTENSIONS.contains(&extension))
+}
+
+/// Get bodies of `<script type="importmap">` elements.
+fn get_inline_import_maps(content: &str) -> Vec<String> {
+    let script_pattern =
+        Regex::new(r#"(?is)<script\b[^>]*\btype\s*=\s*["']?importmap["']?[^>]*>(.*?)</script\s*>"#)
+            .unwrap();
+
+    script_pattern
+        .captures_iter(content)
+        .filter_map(|captures| captures.get(1))
+        .map(|body| body.as_str().to_owned())
+        .collect()
+}
+
+/// Get specifiers mapped by top level `imports` and by every `scopes` entry.
+fn get_mapped_specifiers(import_map: &Value) -> Vec<String> {
+    let imports = import_map.get("imports").into_iter();
+
+    let scopes = import_map
+        .get("scopes")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(|scopes| scopes.values());
+
+    imports
+        .chain(scopes)
+        .filter_map(Value::as_object)
+        .flat_map(|specifier_map| specifier_map.keys().cloned())
+        .collect()
+}
+
+/// Check if a specifier is a package name rather than a path or an URL like `https://...`.
+fn is_bare_specifier(specifier: &str) -> bool {
+    is_external_specifier(specifier) && !specifier.contains(':')
+}
@@ -0,0 +1,76 @@
+use serde_json::Value;
+use std::path::Path;
+
+use crate::special::config_file::{load_config_file, load_package_field};
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+const CONFIG_FILES: [&str; 5] = [
+    "jest.config.js",
+    "jest.config.cjs",
+    "jest.config.mjs",
+    "jest.config.ts",
+    "jest.config.json",
+];
+
+/// The manifest containing a config in the `jest` field.
+const PACKAGE_FILE: &str = "package.json";
+
+/// Detects packages referenced by the jest config.
+pub struct Jest;
+
+impl Special for Jest {
+    fn name(&self) -> &'static str {
+        "jest"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| {
+            CONFIG_FILES.contains(&file_name) || file_name == PACKAGE_FILE
+        })
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let config = if get_file_name(path) == Some(PACKAGE_FILE) {
+            load_package_field(path, "jest")
+        } else {
+            load_config_file(path, context.parser)
+        };
+
+        let config = match config {
+            Some(config) => config,
+            None => return Vec::new(),
+        };
+
+        config
+            .get("moduleNameMapper")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flat_map(|mapper| mapper.values())
+            .flat_map(|target| match target {
+                Value::String(target) => vec![target.as_str()],
+                Value::Array(targets) => targets.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            })
+            .filter_map(get_mapped_package)
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect()
+    }
+}
+
+/// Get a package of a `moduleNameMapper` target like `identity-obj-proxy`
+/// or `<rootDir>/node_modules/jest-canvas-mock`.
+fn get_mapped_package(target: &str) -> Option<String> {
+    let target = target.trim_start_matches("<rootDir>/");
+    let target = match target.rsplit_once("node_modules/") {
+        Some((_, target)) => target,
+        None => target,
+    };
+
+    if !is_external_specifier(target) || target.starts_with('$') {
+        return None;
+    }
+
+    extract_package_name(target)
+}
@@ -0,0 +1,176 @@
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use swc_ecma_ast::{
+    BlockStmtOrExpr, Decl, Expr, Lit, MemberProp, Module, ModuleDecl, ModuleItem, ObjectLit, Pat,
+    PatOrExpr, Prop, PropName, PropOrSpread, Stmt,
+};
+
+/// The limit of nested expressions to evaluate, protects against cyclic variables.
+const MAX_DEPTH: usize = 64;
+
+/// Evaluates static expressions of a module to a JSON values.
+pub struct StaticEvaluator<'a> {
+    variables: HashMap<String, &'a Expr>,
+}
+
+impl<'a> StaticEvaluator<'a> {
+    pub fn new(module: &'a Module) -> Self {
+        let variables = module
+            .body
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => Some(var),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+                    Decl::Var(var) => Some(var),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .flat_map(|var| var.decls.iter())
+            .filter_map(|declarator| match (&declarator.name, &declarator.init) {
+                (Pat::Ident(binding), Some(init)) => Some((binding.id.sym.to_string(), &**init)),
+                _ => None,
+            })
+            .collect();
+
+        StaticEvaluator { variables }
+    }
+
+    /// Get a static value of an expression, non static parts are omitted.
+    pub fn evaluate(&self, expr: &Expr) -> Option<Value> {
+        self.evaluate_with_depth(expr, 0)
+    }
+
+    fn evaluate_variable_with_depth(&self, name: &str, depth: usize) -> Option<Value> {
+        self.variables
+            .get(name)
+            .and_then(|expr| self.evaluate_with_depth(expr, depth + 1))
+    }
+
+    fn evaluate_with_depth(&self, expr: &Expr, depth: usize) -> Option<Value> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        match expr {
+            Expr::Lit(Lit::Str(string)) => Some(Value::String(string.value.to_string())),
+            Expr::Lit(Lit::Bool(boolean)) => Some(Value::Bool(boolean.value)),
+            Expr::Lit(Lit::Num(number)) => Number::from_f64(number.value).map(Value::Number),
+            Expr::Lit(Lit::Null(_)) => Some(Value::Null),
+            Expr::Array(array) => Some(Value::Array(
+                array
+                    .elems
+                    .iter()
+                    .flatten()
+                    .filter(|element| element.spread.is_none())
+                    .filter_map(|element| self.evaluate_with_depth(&element.expr, depth + 1))
+                    .collect(),
+            )),
+            Expr::Object(object) => Some(Value::Object(self.evaluate_object(object, depth))),
+            Expr::Ident(ident) => self.evaluate_variable_with_depth(&ident.sym, depth),
+            Expr::Paren(paren) => self.evaluate_with_depth(&paren.expr, depth + 1),
+            Expr::TsAs(ts_as) => self.evaluate_with_depth(&ts_as.expr, depth + 1),
+            Expr::TsConstAssertion(assertion) => {
+                self.evaluate_with_depth(&assertion.expr, depth + 1)
+            }
+            _ => None,
+        }
+    }
+
+    fn evaluate_object(&self, object: &ObjectLit, depth: usize) -> Map<String, Value> {
+        object
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(key_value) => get_prop_name(&key_value.key).and_then(|key| {
+                        self.evaluate_with_depth(&key_value.value, depth + 1)
+                            .map(|value| (key, value))
+                    }),
+                    Prop::Shorthand(ident) => self
+                        .evaluate_variable_with_depth(&ident.sym, depth)
+                        .map(|value| (ident.sym.to_string(), value)),
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Get a static value of a config exported by a JavaScript or TypeScript module.
+///
+/// Supports `module.exports = ...` and `export default ...` exports, the exported value
+/// can be wrapped by a call like `defineConfig(...)` or by an arrow function.
+pub fn get_exported_config(module: &Module) -> Option<Value> {
+    let evaluator = StaticEvaluator::new(module);
+
+    module
+        .body
+        .iter()
+        .rev()
+        .find_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => Some(&*export.expr),
+            ModuleItem::Stmt(Stmt::Expr(statement)) => match &*statement.expr {
+                Expr::Assign(assign) if is_module_exports(&assign.left) => Some(&*assign.right),
+                _ => None,
+            },
+            _ => None,
+        })
+        .and_then(|expr| evaluator.evaluate(unwrap_config(expr, &evaluator)))
+}
+
+/// Unwrap config wrappers like `defineConfig({...})` or `() => ({...})`.
+fn unwrap_config<'a>(expr: &'a Expr, evaluator: &StaticEvaluator<'a>) -> &'a Expr {
+    let mut expr = expr;
+
+    for _ in 0..MAX_DEPTH {
+        expr = match expr {
+            Expr::Call(call) => match call.args.first() {
+                Some(argument) => &*argument.expr,
+                None => break,
+            },
+            Expr::Arrow(arrow) => match &arrow.body {
+                BlockStmtOrExpr::Expr(body) => &**body,
+                BlockStmtOrExpr::BlockStmt(_) => break,
+            },
+            Expr::Paren(paren) => &*paren.expr,
+            Expr::Ident(ident) => match evaluator.variables.get(&*ident.sym) {
+                Some(variable) => *variable,
+                None => break,
+            },
+            _ => break,
+        };
+    }
+
+    expr
+}
+
+fn is_module_exports(left: &PatOrExpr) -> bool {
+    let expr = match left {
+        PatOrExpr::Expr(expr) => &**expr,
+        PatOrExpr::Pat(pat) => match &**pat {
+            Pat::Expr(expr) => &**expr,
+            _ => return false,
+        },
+    };
+
+    match expr {
+        Expr::Member(member) => matches!(
+            (&*member.obj, &member.prop),
+            (Expr::Ident(object), MemberProp::Ident(property))
+                if &*object.sym == "module" && &*property.sym == "exports"
+        ),
+        _ => false,
+    }
+}
+
+/// Get a static name of a property key.
+pub fn get_prop_name(name: &PropName) -> Option<String> {
+    match name {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(string) => Some(string.value.to_string()),
+        PropName::Num(number) => Some(number.value.to_string()),
+        _ => None,
+    }
+}
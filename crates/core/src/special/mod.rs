@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::package::Package;
+use crate::parser::Parser;
+use crate::util::is_bin_dependency::BinDependencies;
+
+pub(crate) mod babel;
+pub(crate) mod config_file;
+pub(crate) mod cypress;
+pub(crate) mod graphql_codegen;
+pub(crate) mod import_map;
+pub(crate) mod jest;
+pub(crate) mod js_config;
+pub(crate) mod module_federation;
+pub(crate) mod openapi;
+pub(crate) mod playwright;
+pub(crate) mod plugin_arrays;
+pub(crate) mod proto;
+pub(crate) mod provide_plugin;
+pub(crate) mod renovate;
+pub(crate) mod shell;
+pub(crate) mod vitest;
+pub(crate) mod workbox;
+
+/// A detector of dependencies referenced by tool configs rather than by imports.
+pub trait Special: Send + Sync {
+    /// The special name used to enable it in config.
+    fn name(&self) -> &'static str;
+
+    /// Check if a file is handled by the special.
+    fn is_match(&self, path: &Path) -> bool;
+
+    /// Get dependencies used by a matched file.
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String>;
+
+    /// Get source files referenced by a matched file which should be checked as well.
+    fn entry_files(&self, _path: &Path, _context: &SpecialContext) -> Vec<PathBuf> {
+        Vec::new()
+    }
+}
+
+/// Shared state available to specials.
+pub struct SpecialContext<'a> {
+    pub parser: &'a Parser,
+    pub package: &'a Package,
+    pub config: &'a Config,
+    pub directory: &'a Path,
+    /// Bins of installed dependencies shared by files of the check.
+    pub bin_dependencies: &'a BinDependencies,
+}
+
+/// Get all specials enabled by config.
+pub fn get_specials(config: &Config) -> Vec<Box<dyn Special>> {
+    let specials: Vec<Box<dyn Special>> = vec![
+        Box::new(babel::Babel),
+        Box::new(cypress::Cypress),
+        Box::new(graphql_codegen::GraphqlCodegen),
+        Box::new(import_map::ImportMap),
+        Box::new(jest::Jest),
+        Box::new(module_federation::ModuleFederation),
+        Box::new(openapi::OpenApi),
+        Box::new(playwright::Playwright),
+        Box::new(proto::Proto),
+        Box::new(provide_plugin::ProvidePlugin),
+        Box::new(renovate::Renovate),
+        Box::new(shell::Shell),
+        Box::new(vitest::Vitest),
+        Box::new(workbox::Workbox),
+    ];
+
+    let mut specials = specials
+        .into_iter()
+        .filter(|special| config.is_special_enabled(special.name()))
+        .collect::<Vec<_>>();
+
+    // plugin arrays are enabled by their globs
+    if !config.get_plugin_arrays().is_empty() {
+        specials.push(Box::new(plugin_arrays::PluginArrays::new(config)));
+    }
+
+    specials
+}
+
+/// Get a file name of a path.
+pub fn get_file_name(path: &Path) -> Option<&str> {
+    path.file_name()?.to_str()
+}
@@ -0,0 +1,102 @@
+use std::path::Path;
+use swc_ecma_ast::{Expr, KeyValueProp, Lit, Prop, PropOrSpread};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::special::js_config::get_prop_name;
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+const CONFIG_FILES: [&str; 8] = [
+    "webpack.config.js",
+    "webpack.config.cjs",
+    "webpack.config.mjs",
+    "webpack.config.ts",
+    "module-federation.config.js",
+    "module-federation.config.cjs",
+    "module-federation.config.mjs",
+    "module-federation.config.ts",
+];
+
+/// Detects packages shared between federated modules by `shared` of a Module Federation
+/// config, like `new ModuleFederationPlugin({ shared: { react: { singleton: true } } })`.
+pub struct ModuleFederation;
+
+impl Special for ModuleFederation {
+    fn name(&self) -> &'static str {
+        "module-federation"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| CONFIG_FILES.contains(&file_name))
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let module = match context.parser.parse_file(path) {
+            Some((module, _)) => module,
+            None => return Vec::new(),
+        };
+
+        // the plugin options are usually passed to a constructor rather than exported,
+        // so `shared` objects are collected anywhere in the module
+        let mut collector = SharedCollector::default();
+        module.visit_with(&mut collector);
+
+        let mut dependencies = collector
+            .specifiers
+            .iter()
+            .filter(|specifier| is_external_specifier(specifier))
+            .filter_map(|specifier| extract_package_name(specifier))
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect::<Vec<_>>();
+
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
+/// Collects keys of `shared` objects and strings of `shared` arrays.
+#[derive(Default)]
+struct SharedCollector {
+    specifiers: Vec<String>,
+}
+
+impl Visit for SharedCollector {
+    fn visit_key_value_prop(&mut self, key_value: &KeyValueProp) {
+        if get_prop_name(&key_value.key).as_deref() == Some("shared") {
+            self.specifiers
+                .extend(get_shared_specifiers(&key_value.value));
+        }
+
+        key_value.visit_children_with(self);
+    }
+}
+
+fn get_shared_specifiers(expr: &Expr) -> Vec<String> {
+    match expr {
+        Expr::Object(object) => object
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(key_value) => get_prop_name(&key_value.key),
+                    Prop::Shorthand(ident) => Some(ident.sym.to_string()),
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .collect(),
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .flatten()
+            .filter(|element| element.spread.is_none())
+            .filter_map(|element| match &*element.expr {
+                Expr::Lit(Lit::Str(string)) => Some(string.value.to_string()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,136 @@
+use serde_json::Value;
+use std::path::Path;
+
+use crate::special::config_file::load_config_file;
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+/// The config of openapi-generator-cli.
+const OPENAPI_TOOLS_FILE: &str = "openapitools.json";
+
+/// The package reading `openapitools.json`.
+const OPENAPI_TOOLS_PACKAGE: &str = "@openapitools/openapi-generator-cli";
+
+const ORVAL_CONFIG_FILES: [&str; 5] = [
+    "orval.config.js",
+    "orval.config.cjs",
+    "orval.config.mjs",
+    "orval.config.ts",
+    "orval.config.json",
+];
+
+/// The package reading `orval.config.js`.
+const ORVAL_PACKAGE: &str = "orval";
+
+/// The client orval generates when an output doesn't set one.
+const ORVAL_DEFAULT_CLIENT: &str = "axios";
+
+/// Packages imported by generated mocks.
+const ORVAL_MOCK_PACKAGES: [&str; 2] = ["msw", "@faker-js/faker"];
+
+/// Detects generators referenced by `openapitools.json` and clients generated by orval.
+pub struct OpenApi;
+
+impl Special for OpenApi {
+    fn name(&self) -> &'static str {
+        "openapi"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| {
+            file_name == OPENAPI_TOOLS_FILE || ORVAL_CONFIG_FILES.contains(&file_name)
+        })
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let config = match load_config_file(path, context.parser) {
+            Some(config) => config,
+            None => return Vec::new(),
+        };
+
+        let mut dependencies = if get_file_name(path) == Some(OPENAPI_TOOLS_FILE) {
+            get_openapi_tools_packages(&config)
+        } else {
+            get_orval_packages(&config)
+        };
+
+        dependencies.retain(|dependency| context.package.is_any_dependency(dependency));
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
+/// Get the generator cli and packages of the `$schema` like
+/// `node_modules/@openapitools/openapi-generator-cli/config.schema.json`.
+fn get_openapi_tools_packages(config: &Value) -> Vec<String> {
+    let schema = config
+        .get("$schema")
+        .and_then(Value::as_str)
+        .and_then(|schema| schema.rsplit_once("node_modules/"))
+        .map(|(_, specifier)| specifier)
+        .filter(|specifier| is_external_specifier(specifier))
+        .and_then(extract_package_name);
+
+    schema
+        .into_iter()
+        .chain(Some(String::from(OPENAPI_TOOLS_PACKAGE)))
+        .collect()
+}
+
+/// Get orval and packages imported by clients and mocks generated for each project.
+fn get_orval_packages(config: &Value) -> Vec<String> {
+    let outputs = config
+        .as_object()
+        .into_iter()
+        .flat_map(|projects| projects.values())
+        .filter_map(|project| project.get("output"));
+
+    let generated = outputs.flat_map(|output| {
+        // an output is either a target path or an object of options
+        let client = match output {
+            Value::String(_) => Some(ORVAL_DEFAULT_CLIENT),
+            output => match output.get("client") {
+                Some(client) => client.as_str(),
+                None => Some(ORVAL_DEFAULT_CLIENT),
+            },
+        };
+
+        let mock = match output.get("mock") {
+            Some(Value::Bool(mock)) => *mock,
+            Some(Value::Object(_)) => true,
+            _ => false,
+        };
+
+        let mock_packages = if mock {
+            ORVAL_MOCK_PACKAGES.to_vec()
+        } else {
+            Vec::new()
+        };
+
+        client
+            .into_iter()
+            .flat_map(get_client_packages)
+            .copied()
+            .chain(mock_packages)
+            .map(String::from)
+            .collect::<Vec<_>>()
+    });
+
+    generated.chain(Some(String::from(ORVAL_PACKAGE))).collect()
+}
+
+/// Get packages imported by code of an orval client.
+fn get_client_packages(client: &str) -> &'static [&'static str] {
+    match client {
+        "axios" | "axios-functions" => &["axios"],
+        "angular" => &["@angular/common"],
+        "react-query" => &["@tanstack/react-query", "react-query"],
+        "svelte-query" => &["@tanstack/svelte-query"],
+        "vue-query" => &["@tanstack/vue-query"],
+        "swr" => &["swr"],
+        "zod" => &["zod"],
+        _ => &[],
+    }
+}
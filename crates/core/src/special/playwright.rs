@@ -0,0 +1,77 @@
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+use crate::special::config_file::load_config_file;
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+const CONFIG_FILES: [&str; 4] = [
+    "playwright.config.js",
+    "playwright.config.cjs",
+    "playwright.config.mjs",
+    "playwright.config.ts",
+];
+
+/// Options of scripts run before and after all tests.
+const GLOBAL_SCRIPT_OPTIONS: [&str; 2] = ["globalSetup", "globalTeardown"];
+
+/// Detects reporters referenced by the playwright config and checks its global scripts.
+pub struct Playwright;
+
+impl Special for Playwright {
+    fn name(&self) -> &'static str {
+        "playwright"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| CONFIG_FILES.contains(&file_name))
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let config = match load_config_file(path, context.parser) {
+            Some(config) => config,
+            None => return Vec::new(),
+        };
+
+        // reporters are either a name or a list of `[name, options]` tuples
+        let reporters = match config.get("reporter") {
+            Some(Value::String(reporter)) => vec![reporter.as_str()],
+            Some(Value::Array(reporters)) => reporters
+                .iter()
+                .filter_map(|reporter| match reporter {
+                    Value::Array(reporter) => reporter.first().and_then(Value::as_str),
+                    reporter => reporter.as_str(),
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        reporters
+            .into_iter()
+            .filter(|reporter| is_external_specifier(reporter))
+            .filter_map(extract_package_name)
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect()
+    }
+
+    fn entry_files(&self, path: &Path, context: &SpecialContext) -> Vec<PathBuf> {
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        load_config_file(path, context.parser)
+            .map(|config| {
+                GLOBAL_SCRIPT_OPTIONS
+                    .iter()
+                    .filter_map(|option| config.get(*option))
+                    .flat_map(|scripts| match scripts {
+                        Value::Array(scripts) => scripts.iter().collect(),
+                        script => vec![script],
+                    })
+                    .filter_map(Value::as_str)
+                    .filter(|script| !is_external_specifier(script))
+                    .map(|script| directory.join(script))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,147 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::path::{Path, PathBuf};
+use swc_ecma_ast::{ArrayLit, Expr, KeyValueProp, Lit, Pat, VarDeclarator};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::config::Config;
+use crate::special::js_config::get_prop_name;
+use crate::special::{Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+/// Detects installed packages named by string literals of arrays like `plugins: ['pkg']`
+/// in config files matched by globs of `Config::with_plugin_arrays`.
+pub struct PluginArrays {
+    directory: PathBuf,
+    globs: GlobSet,
+    /// Names of arrays by indexes of globs.
+    names: Vec<Vec<String>>,
+}
+
+impl PluginArrays {
+    pub fn new(config: &Config) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        let mut names = Vec::new();
+
+        for (pattern, array_names) in config.get_plugin_arrays() {
+            match Glob::new(pattern) {
+                Ok(glob) => {
+                    builder.add(glob);
+                    names.push(array_names.to_owned());
+                }
+                Err(error) => log::warn!("malformed plugin array glob {}: {:#?}", pattern, error),
+            }
+        }
+
+        let globs = builder.build().unwrap_or_else(|error| {
+            log::warn!("failed to build plugin array globs: {:#?}", error);
+            names.clear();
+            GlobSet::empty()
+        });
+
+        PluginArrays {
+            directory: config.get_directory().to_path_buf(),
+            globs,
+            names,
+        }
+    }
+
+    /// Get names of arrays to scan in a file.
+    fn get_array_names(&self, path: &Path) -> Vec<&str> {
+        let path = path.strip_prefix(&self.directory).unwrap_or(path);
+
+        self.globs
+            .matches(path)
+            .into_iter()
+            .flat_map(|index| self.names[index].iter().map(String::as_str))
+            .collect()
+    }
+}
+
+impl Special for PluginArrays {
+    fn name(&self) -> &'static str {
+        "plugin-arrays"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        let path = path.strip_prefix(&self.directory).unwrap_or(path);
+        self.globs.is_match(path)
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let module = match context.parser.parse_file(path) {
+            Some((module, _)) => module,
+            None => return Vec::new(),
+        };
+
+        let mut collector = ArrayCollector {
+            names: self.get_array_names(path),
+            specifiers: Vec::new(),
+        };
+        module.visit_with(&mut collector);
+
+        let node_modules = context.directory.join("node_modules");
+
+        let mut dependencies = collector
+            .specifiers
+            .iter()
+            .filter(|specifier| is_external_specifier(specifier))
+            .filter_map(|specifier| extract_package_name(specifier))
+            .filter(|dependency| node_modules.join(dependency).is_dir())
+            .collect::<Vec<_>>();
+
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
+/// Collects string literals of arrays assigned to properties or variables of names.
+struct ArrayCollector<'a> {
+    names: Vec<&'a str>,
+    specifiers: Vec<String>,
+}
+
+impl ArrayCollector<'_> {
+    fn collect_array(&mut self, name: &str, expr: &Expr) {
+        if let (true, Expr::Array(array)) = (self.names.contains(&name), expr) {
+            self.specifiers.extend(get_array_strings(array));
+        }
+    }
+}
+
+impl Visit for ArrayCollector<'_> {
+    fn visit_key_value_prop(&mut self, key_value: &KeyValueProp) {
+        if let Some(name) = get_prop_name(&key_value.key) {
+            self.collect_array(&name, &key_value.value);
+        }
+
+        key_value.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, &declarator.init) {
+            self.collect_array(&binding.id.sym, init);
+        }
+
+        declarator.visit_children_with(self);
+    }
+}
+
+/// Get string literals of an array, entries may also be `['name', options]` tuples.
+fn get_array_strings(array: &ArrayLit) -> Vec<String> {
+    array
+        .elems
+        .iter()
+        .flatten()
+        .filter(|element| element.spread.is_none())
+        .filter_map(|element| match &*element.expr {
+            Expr::Array(tuple) => tuple.elems.first()?.as_ref().map(|first| &*first.expr),
+            expr => Some(expr),
+        })
+        .filter_map(|expr| match expr {
+            Expr::Lit(Lit::Str(string)) => Some(string.value.to_string()),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,49 @@
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+use crate::special::{Special, SpecialContext};
+
+/// Detects packages providing protobuf files imported by `.proto` files.
+///
+/// Imports are mapped to packages by path prefixes of `Config::with_proto_package_map`.
+pub struct Proto;
+
+impl Special for Proto {
+    fn name(&self) -> &'static str {
+        "proto"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        path.extension()
+            .map_or(false, |extension| extension == "proto")
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) => {
+                log::error!("failed to read {:#?}: {:#?}", path, error);
+                return Vec::new();
+            }
+        };
+
+        let import_pattern =
+            Regex::new(r#"(?m)^\s*import\s+(?:public\s+|weak\s+)?"([^"]+)"\s*;"#).unwrap();
+        let proto_package_map = context.config.get_proto_package_map();
+
+        import_pattern
+            .captures_iter(&content)
+            .filter_map(|captures| captures.get(1))
+            .filter_map(|import| {
+                // the longest prefix is the most specific mapping
+                proto_package_map
+                    .iter()
+                    .filter(|(prefix, _)| import.as_str().starts_with(prefix.as_str()))
+                    .max_by_key(|(prefix, _)| prefix.len())
+                    .map(|(_, package)| package.to_owned())
+            })
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect()
+    }
+}
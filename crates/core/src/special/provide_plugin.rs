@@ -0,0 +1,106 @@
+use std::path::Path;
+use swc_ecma_ast::{Expr, Lit, MemberProp, NewExpr, Prop, PropOrSpread};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+const CONFIG_FILES: [&str; 4] = [
+    "webpack.config.js",
+    "webpack.config.cjs",
+    "webpack.config.mjs",
+    "webpack.config.ts",
+];
+
+/// Detects packages injected as free variables by webpack `ProvidePlugin`,
+/// like `new webpack.ProvidePlugin({ $: 'jquery', map: ['lodash', 'map'] })`.
+pub struct ProvidePlugin;
+
+impl Special for ProvidePlugin {
+    fn name(&self) -> &'static str {
+        "provide-plugin"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| CONFIG_FILES.contains(&file_name))
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let module = match context.parser.parse_file(path) {
+            Some((module, _)) => module,
+            None => return Vec::new(),
+        };
+
+        let mut collector = ProvidedCollector::default();
+        module.visit_with(&mut collector);
+
+        let mut dependencies = collector
+            .specifiers
+            .iter()
+            .filter(|specifier| is_external_specifier(specifier))
+            .filter_map(|specifier| extract_package_name(specifier))
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect::<Vec<_>>();
+
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
+/// Collects modules of `ProvidePlugin` options.
+#[derive(Default)]
+struct ProvidedCollector {
+    specifiers: Vec<String>,
+}
+
+impl Visit for ProvidedCollector {
+    fn visit_new_expr(&mut self, new: &NewExpr) {
+        let options = new
+            .args
+            .iter()
+            .flatten()
+            .next()
+            .filter(|_| is_provide_plugin(&new.callee));
+
+        if let Some(Expr::Object(object)) = options.map(|options| &*options.expr) {
+            let values = object.props.iter().filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(key_value) => Some(&*key_value.value),
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            });
+
+            self.specifiers
+                .extend(values.filter_map(get_provided_module));
+        }
+
+        new.visit_children_with(self);
+    }
+}
+
+/// Check if a constructor is `ProvidePlugin` or a member like `webpack.ProvidePlugin`.
+fn is_provide_plugin(callee: &Expr) -> bool {
+    match callee {
+        Expr::Ident(ident) => &*ident.sym == "ProvidePlugin",
+        Expr::Member(member) => {
+            matches!(&member.prop, MemberProp::Ident(prop) if &*prop.sym == "ProvidePlugin")
+        }
+        _ => false,
+    }
+}
+
+/// Get the module of a provided value, which is a module or a `[module, ...property path]`.
+fn get_provided_module(value: &Expr) -> Option<String> {
+    let module = match value {
+        Expr::Array(array) => &*array.elems.first()?.as_ref()?.expr,
+        value => value,
+    };
+
+    match module {
+        Expr::Lit(Lit::Str(string)) => Some(string.value.to_string()),
+        _ => None,
+    }
+}
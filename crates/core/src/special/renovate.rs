@@ -0,0 +1,108 @@
+use serde_json::Value;
+use std::path::Path;
+
+use crate::special::config_file::{load_config_file, load_package_field};
+use crate::special::{get_file_name, Special, SpecialContext};
+
+/// Hidden configs like `.renovaterc` are walked with `Config::with_scan_hidden`.
+const CONFIG_FILES: [&str; 3] = ["renovate.json", ".renovaterc", ".renovaterc.json"];
+
+/// The manifest containing a config in the `renovate` field.
+const PACKAGE_FILE: &str = "package.json";
+
+/// Namespaces of presets built into renovate like `config:base`.
+const BUILT_IN_NAMESPACES: [&str; 18] = [
+    "abandonments",
+    "config",
+    "customManagers",
+    "default",
+    "docker",
+    "github",
+    "group",
+    "helpers",
+    "mergeConfidence",
+    "monorepo",
+    "npm",
+    "packages",
+    "preview",
+    "regexManagers",
+    "replacements",
+    "schedule",
+    "security",
+    "workarounds",
+];
+
+/// The prefix of unscoped shareable preset packages.
+const PRESET_PREFIX: &str = "renovate-config-";
+
+/// The name of shareable preset packages of scopes like `@org/renovate-config`.
+const SCOPED_PRESET_NAME: &str = "renovate-config";
+
+/// Detects shareable preset packages extended by the renovate config.
+pub struct Renovate;
+
+impl Special for Renovate {
+    fn name(&self) -> &'static str {
+        "renovate"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| {
+            CONFIG_FILES.contains(&file_name) || file_name == PACKAGE_FILE
+        })
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let config = if get_file_name(path) == Some(PACKAGE_FILE) {
+            load_package_field(path, "renovate")
+        } else {
+            load_config_file(path, context.parser)
+        };
+
+        let mut dependencies = config
+            .as_ref()
+            .and_then(|config| config.get("extends"))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter_map(get_preset_package)
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect::<Vec<_>>();
+
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
+/// Get a package of a preset like `foo` for `renovate-config-foo` or `@org:preset` for
+/// `@org/renovate-config`. Built-in presets and presets hosted by git platforms like
+/// `github>org/repo` aren't packages.
+fn get_preset_package(preset: &str) -> Option<String> {
+    if preset.starts_with(':') || preset.contains('>') {
+        return None;
+    }
+
+    // parameters like `:pinVersions(!widgets)` may follow the name
+    let preset = preset.split('(').next().unwrap_or(preset);
+
+    let (name, _) = preset.split_once(':').unwrap_or((preset, ""));
+
+    if let Some(scoped_name) = name.strip_prefix('@') {
+        return match scoped_name.split_once('/') {
+            Some((scope, package)) => Some(format!("@{scope}/{package}")),
+            None => Some(format!("{name}/{SCOPED_PRESET_NAME}")),
+        };
+    }
+
+    if BUILT_IN_NAMESPACES.contains(&name) || name.is_empty() {
+        return None;
+    }
+
+    if name.starts_with(PRESET_PREFIX) {
+        Some(name.to_owned())
+    } else {
+        Some(format!("{PRESET_PREFIX}{name}"))
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::special::{get_file_name, Special, SpecialContext};
+
+const MAKEFILES: [&str; 3] = ["Makefile", "makefile", "GNUmakefile"];
+
+const SCRIPT_EXTENSIONS: [&str; 2] = ["sh", "mk"];
+
+/// Detects packages whose bins are invoked by Dockerfiles, shell scripts and Makefiles.
+///
+/// Only exact command tokens matching bins of installed dependencies are detected.
+pub struct Shell;
+
+impl Special for Shell {
+    fn name(&self) -> &'static str {
+        "shell"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        let is_script = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map_or(false, |extension| SCRIPT_EXTENSIONS.contains(&extension));
+
+        is_script
+            || get_file_name(path).map_or(false, |file_name| {
+                file_name.starts_with("Dockerfile") || MAKEFILES.contains(&file_name)
+            })
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) => {
+                log::error!("failed to read {:#?}: {:#?}", path, error);
+                return Vec::new();
+            }
+        };
+
+        let bins = get_installed_bins(context);
+
+        let mut dependencies = content
+            .split(|character: char| !is_token_character(character))
+            .filter_map(|token| bins.get(get_command_name(token)))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
+/// Get bin names of installed dependencies mapped to their packages, manifests are read once
+/// per check by the shared bins of the context.
+fn get_installed_bins(context: &SpecialContext) -> HashMap<String, String> {
+    let package = context.package;
+
+    package
+        .dependencies
+        .keys()
+        .chain(package.dev_dependencies.keys())
+        .chain(package.peer_dependencies.keys())
+        .chain(package.optional_dependencies.keys())
+        .filter_map(|dependency| {
+            context
+                .bin_dependencies
+                .get_bin_names(dependency)
+                .map(|bin_names| (dependency, bin_names))
+        })
+        .flat_map(|(dependency, bin_names)| {
+            bin_names
+                .into_iter()
+                .map(move |bin_name| (bin_name, dependency.to_owned()))
+        })
+        .collect()
+}
+
+fn is_token_character(character: char) -> bool {
+    character.is_alphanumeric() || matches!(character, '-' | '_' | '.' | '@' | '/')
+}
+
+/// Get a command name of a token, `node_modules/.bin/tsc` is `tsc`.
+fn get_command_name(token: &str) -> &str {
+    match token.rsplit_once("node_modules/.bin/") {
+        Some((_, command)) => command,
+        None => token,
+    }
+}
@@ -0,0 +1,166 @@
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+use crate::special::config_file::load_config_file;
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+/// Configs of vitest, vite configs may contain a vitest config in the `test` option.
+const CONFIG_FILES: [&str; 12] = [
+    "vitest.config.js",
+    "vitest.config.cjs",
+    "vitest.config.mjs",
+    "vitest.config.ts",
+    "vitest.config.cts",
+    "vitest.config.mts",
+    "vite.config.js",
+    "vite.config.cjs",
+    "vite.config.mjs",
+    "vite.config.ts",
+    "vite.config.cts",
+    "vite.config.mts",
+];
+
+/// Options of files run before tests.
+const SETUP_FILE_OPTIONS: [&str; 2] = ["setupFiles", "globalSetup"];
+
+/// Reporters built into vitest, other reporters are packages.
+const BUILTIN_REPORTERS: [&str; 10] = [
+    "default",
+    "basic",
+    "verbose",
+    "dot",
+    "json",
+    "junit",
+    "tap",
+    "tap-flat",
+    "hanging-process",
+    "github-actions",
+];
+
+/// Detects coverage providers, environments and reporters named by the vitest config
+/// and checks its setup files.
+pub struct Vitest;
+
+impl Special for Vitest {
+    fn name(&self) -> &'static str {
+        "vitest"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| CONFIG_FILES.contains(&file_name))
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let test = match load_test_config(path, context) {
+            Some(test) => test,
+            None => return Vec::new(),
+        };
+
+        let coverage_packages = test
+            .pointer("/coverage/provider")
+            .and_then(Value::as_str)
+            .and_then(|provider| match provider {
+                "v8" | "c8" | "istanbul" => Some(format!("@vitest/coverage-{provider}")),
+                _ => None,
+            });
+
+        let custom_coverage_packages = test
+            .pointer("/coverage/customProviderModule")
+            .and_then(Value::as_str)
+            .and_then(get_package_name);
+
+        let environment_packages = test
+            .get("environment")
+            .and_then(Value::as_str)
+            .and_then(get_environment_package);
+
+        let browser_packages = test
+            .pointer("/browser/provider")
+            .and_then(Value::as_str)
+            .and_then(|provider| match provider {
+                "playwright" | "webdriverio" => Some(String::from(provider)),
+                _ => None,
+            });
+
+        // reporters are either a name or a list of names and `[name, options]` tuples
+        let reporters = match test.get("reporters") {
+            Some(Value::String(reporter)) => vec![reporter.as_str()],
+            Some(Value::Array(reporters)) => reporters
+                .iter()
+                .filter_map(|reporter| match reporter {
+                    Value::Array(reporter) => reporter.first().and_then(Value::as_str),
+                    reporter => reporter.as_str(),
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let reporter_packages = reporters.into_iter().filter_map(|reporter| match reporter {
+            // the html reporter is the ui package
+            "html" => Some(String::from("@vitest/ui")),
+            reporter if BUILTIN_REPORTERS.contains(&reporter) => None,
+            reporter => get_package_name(reporter),
+        });
+
+        let mut dependencies = coverage_packages
+            .into_iter()
+            .chain(custom_coverage_packages)
+            .chain(environment_packages)
+            .chain(browser_packages)
+            .chain(reporter_packages)
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect::<Vec<_>>();
+
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+
+    fn entry_files(&self, path: &Path, context: &SpecialContext) -> Vec<PathBuf> {
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        load_test_config(path, context)
+            .map(|test| {
+                SETUP_FILE_OPTIONS
+                    .iter()
+                    .filter_map(|option| test.get(*option))
+                    .flat_map(|files| match files {
+                        Value::Array(files) => files.iter().collect(),
+                        file => vec![file],
+                    })
+                    .filter_map(Value::as_str)
+                    .filter(|file| !is_external_specifier(file))
+                    .map(|file| directory.join(file))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Load the `test` option of a vitest or vite config.
+fn load_test_config(path: &Path, context: &SpecialContext) -> Option<Value> {
+    load_config_file(path, context.parser)?.get("test").cloned()
+}
+
+/// Get a package of a test environment like `jsdom` or `vitest-environment-custom`.
+fn get_environment_package(environment: &str) -> Option<String> {
+    match environment {
+        "node" => None,
+        "jsdom" | "happy-dom" => Some(String::from(environment)),
+        "edge-runtime" => Some(String::from("@edge-runtime/vm")),
+        environment if is_external_specifier(environment) && !environment.contains('/') => {
+            Some(format!("vitest-environment-{environment}"))
+        }
+        _ => None,
+    }
+}
+
+fn get_package_name(specifier: &str) -> Option<String> {
+    if !is_external_specifier(specifier) {
+        return None;
+    }
+
+    extract_package_name(specifier)
+}
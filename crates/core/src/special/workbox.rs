@@ -0,0 +1,104 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::special::config_file::load_config_file;
+use crate::special::{get_file_name, Special, SpecialContext};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_external_specifier::is_external_specifier;
+
+const CONFIG_FILES: [&str; 3] = [
+    "workbox-config.js",
+    "workbox-config.cjs",
+    "workbox-config.mjs",
+];
+
+/// Packages reading `workbox-config.js`.
+const CONFIG_PACKAGES: [&str; 2] = ["workbox-cli", "workbox-build"];
+
+/// Packages required by `runtimeCaching` entry options.
+const RUNTIME_CACHING_OPTIONS: [(&str, &str); 6] = [
+    ("expiration", "workbox-expiration"),
+    ("cacheableResponse", "workbox-cacheable-response"),
+    ("backgroundSync", "workbox-background-sync"),
+    ("broadcastUpdate", "workbox-broadcast-update"),
+    ("rangeRequests", "workbox-range-requests"),
+    ("precacheFallback", "workbox-precaching"),
+];
+
+/// Detects workbox packages referenced by `workbox-config.js`.
+pub struct Workbox;
+
+impl Special for Workbox {
+    fn name(&self) -> &'static str {
+        "workbox"
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        get_file_name(path).map_or(false, |file_name| CONFIG_FILES.contains(&file_name))
+    }
+
+    fn detect(&self, path: &Path, context: &SpecialContext) -> Vec<String> {
+        let config = match load_config_file(path, context.parser) {
+            Some(config) => config,
+            None => return Vec::new(),
+        };
+
+        let import_scripts = config
+            .get("importScripts")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(|script| script.trim_start_matches("node_modules/"))
+            .filter(|script| is_external_specifier(script) && !script.contains("://"))
+            .filter_map(extract_package_name);
+
+        let runtime_caching = config
+            .get("runtimeCaching")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .flat_map(|entry| {
+                let strategies = entry
+                    .get("handler")
+                    .and_then(Value::as_str)
+                    .map(|_| String::from("workbox-strategies"));
+
+                let options = entry
+                    .get("options")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flat_map(|options| {
+                        RUNTIME_CACHING_OPTIONS
+                            .iter()
+                            .filter(move |(option, _)| options.contains_key(*option))
+                            .map(|(_, dependency)| dependency.to_string())
+                    });
+
+                strategies.into_iter().chain(options).collect::<Vec<_>>()
+            });
+
+        CONFIG_PACKAGES
+            .iter()
+            .map(|dependency| dependency.to_string())
+            .chain(import_scripts)
+            .chain(runtime_caching)
+            .filter(|dependency| context.package.is_any_dependency(dependency))
+            .collect()
+    }
+
+    fn entry_files(&self, path: &Path, context: &SpecialContext) -> Vec<PathBuf> {
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        load_config_file(path, context.parser)
+            .and_then(|config| {
+                config
+                    .get("swSrc")
+                    .and_then(Value::as_str)
+                    .map(String::from)
+            })
+            .map(|sw_src| vec![directory.join(sw_src)])
+            .unwrap_or_default()
+    }
+}
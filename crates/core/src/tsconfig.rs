@@ -0,0 +1,93 @@
+use crate::error::DepcheckError;
+use crate::util::strip_jsonc::strip_jsonc;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TsConfig {
+    /// The compiler options.
+    #[serde(default)]
+    pub compiler_options: CompilerOptions,
+    /// Referenced projects of a project references build.
+    #[serde(default)]
+    pub references: Vec<ProjectReference>,
+}
+
+/// A project referenced by `references` like `{ "path": "../lib" }`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ProjectReference {
+    /// A directory of the project or a path of its tsconfig file.
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerOptions {
+    /// The base directory to resolve non-relative module names.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Aliases like `@utils/*` mapped to target paths like `src/utils/*`.
+    #[serde(default)]
+    pub paths: BTreeMap<String, Vec<String>>,
+}
+
+impl TsConfig {
+    /// Deserializes a `TsConfig` from a file path.
+    pub fn from_path<T: AsRef<Path>>(path: T) -> Result<Self, DepcheckError> {
+        let path = path.as_ref();
+        let content = fs::read(path).map_err(|error| DepcheckError::io(path, error))?;
+        Self::from_slice(content.as_slice())
+            .map_err(|error| DepcheckError::parse_manifest(path, error))
+    }
+
+    /// Deserializes a `TsConfig` from bytes, allowing comments and trailing commas.
+    pub fn from_slice(v: &[u8]) -> serde_json::Result<Self> {
+        let content = String::from_utf8_lossy(v);
+        serde_json::from_str(&strip_jsonc(&content))
+    }
+}
+
+impl TsConfig {
+    /// Get the `baseUrl` directory resolved against the tsconfig directory.
+    pub fn get_base_url(&self, directory: &Path) -> Option<PathBuf> {
+        self.compiler_options
+            .base_url
+            .as_ref()
+            .map(|base_url| directory.join(base_url))
+    }
+
+    /// Get `paths` aliases with targets resolved against `baseUrl`, or against the tsconfig
+    /// directory when `baseUrl` isn't set.
+    pub fn get_path_aliases(&self, directory: &Path) -> Vec<(String, Vec<PathBuf>)> {
+        let base_url = self
+            .get_base_url(directory)
+            .unwrap_or_else(|| directory.to_path_buf());
+
+        self.compiler_options
+            .paths
+            .iter()
+            .map(|(alias, targets)| {
+                let targets = targets.iter().map(|target| base_url.join(target)).collect();
+                (alias.to_owned(), targets)
+            })
+            .collect()
+    }
+
+    /// Get tsconfig files of referenced projects resolved against the tsconfig directory, a
+    /// reference to a directory refers to its `tsconfig.json`.
+    pub fn get_reference_paths(&self, directory: &Path) -> Vec<PathBuf> {
+        self.references
+            .iter()
+            .map(|reference| {
+                let path = directory.join(&reference.path);
+                match path.extension() {
+                    Some(extension) if extension == "json" => path,
+                    _ => path.join("tsconfig.json"),
+                }
+            })
+            .collect()
+    }
+}
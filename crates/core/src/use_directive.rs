@@ -0,0 +1,34 @@
+use swc_common::comments::{Comment, SingleThreadedComments};
+
+/// A comment directive declaring packages used by a file, like `// depcheck-use: pkg, other`.
+const USE_DIRECTIVE: &str = "depcheck-use:";
+
+/// Get packages declared as used by `depcheck-use` directives of line and block comments.
+pub fn collect_use_directives(comments: &SingleThreadedComments) -> Vec<String> {
+    let (leading, trailing) = comments.borrow_all();
+
+    let mut packages = leading
+        .values()
+        .chain(trailing.values())
+        .flatten()
+        .flat_map(get_directive_packages)
+        .collect::<Vec<_>>();
+
+    packages.sort();
+    packages.dedup();
+    packages
+}
+
+fn get_directive_packages(comment: &Comment) -> Vec<String> {
+    // lines of block comments may be prefixed with `*`
+    comment
+        .text
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim_start())
+        .filter_map(|line| line.strip_prefix(USE_DIRECTIVE))
+        .flat_map(|packages| packages.split(','))
+        .map(str::trim)
+        .filter(|package| !package.is_empty())
+        .map(String::from)
+        .collect()
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::util::load_module::load_module;
+
+/// The limit of nested `node_modules` to walk, deeper installs are skipped.
+const MAX_DEPTH: usize = 8;
+
+/// Get versions of packages installed under `node_modules` of a directory and under
+/// nested `node_modules` of installed packages.
+///
+/// Key is a package name and value is install paths relative to the directory with versions.
+pub fn collect_installs(directory: &Path) -> BTreeMap<String, Vec<(PathBuf, String)>> {
+    let mut installs = BTreeMap::new();
+    collect_node_modules(directory, Path::new("node_modules"), 0, &mut installs);
+    installs
+}
+
+fn collect_node_modules(
+    directory: &Path,
+    node_modules: &Path,
+    depth: usize,
+    installs: &mut BTreeMap<String, Vec<(PathBuf, String)>>,
+) {
+    if depth >= MAX_DEPTH {
+        return;
+    }
+
+    for (name, path) in get_package_directories(&directory.join(node_modules), node_modules) {
+        if let Ok(package) = load_module(&directory.join(&path)) {
+            installs
+                .entry(name)
+                .or_default()
+                .push((path.clone(), package.version));
+        }
+
+        collect_node_modules(directory, &path.join("node_modules"), depth + 1, installs);
+    }
+}
+
+/// Get names and relative paths of package directories of a `node_modules`,
+/// directories of `@scope` are flattened and hidden ones like `.bin` are skipped.
+fn get_package_directories(node_modules: &Path, relative_path: &Path) -> Vec<(String, PathBuf)> {
+    let mut package_directories = Vec::new();
+
+    for name in get_directory_names(node_modules) {
+        if name.starts_with('.') {
+            continue;
+        }
+
+        if name.starts_with('@') {
+            for scoped_name in get_directory_names(&node_modules.join(&name)) {
+                package_directories.push((
+                    format!("{name}/{scoped_name}"),
+                    relative_path.join(&name).join(scoped_name),
+                ));
+            }
+        } else {
+            package_directories.push((name.clone(), relative_path.join(name)));
+        }
+    }
+
+    package_directories
+}
+
+fn get_directory_names(directory: &Path) -> Vec<String> {
+    let mut names = fs::read_dir(directory)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    names.sort();
+    names
+}
@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::tsconfig::TsConfig;
+
+/// Get directories of projects referenced by `references` of the tsconfig of a directory,
+/// including projects referenced by the referenced ones. The directory itself isn't included.
+///
+/// A reference to a tsconfig file like `../lib/tsconfig.build.json` is followed by references
+/// of that file, tsconfig files of the same directory are one project.
+pub fn collect_project_references(directory: &Path) -> Vec<PathBuf> {
+    let root_tsconfig = directory.join("tsconfig.json");
+
+    let mut visited_tsconfigs = HashSet::new();
+    let mut visited_projects = HashSet::new();
+    let mut projects = Vec::new();
+
+    if let Ok(directory) = directory.canonicalize() {
+        visited_projects.insert(directory);
+    }
+
+    if let Ok(root_tsconfig) = root_tsconfig.canonicalize() {
+        visited_tsconfigs.insert(root_tsconfig);
+    }
+
+    let mut pending = vec![root_tsconfig];
+
+    while let Some(tsconfig_path) = pending.pop() {
+        let tsconfig = match TsConfig::from_path(&tsconfig_path) {
+            Ok(tsconfig) => tsconfig,
+            Err(error) => {
+                log::debug!("failed to read tsconfig {:#?}: {:#?}", tsconfig_path, error);
+                continue;
+            }
+        };
+
+        let project = tsconfig_path.parent().unwrap_or_else(|| Path::new(""));
+
+        for reference in tsconfig.get_reference_paths(project) {
+            let reference_project = reference
+                .parent()
+                .map_or_else(|| project.to_path_buf(), Path::to_path_buf);
+
+            // references may form cycles or be shared by several projects
+            let canonical_project = match reference_project.canonicalize() {
+                Ok(canonical_project) => canonical_project,
+                Err(error) => {
+                    log::warn!("failed to resolve reference {:#?}: {:#?}", reference, error);
+                    continue;
+                }
+            };
+
+            if visited_projects.insert(canonical_project) {
+                projects.push(reference_project);
+            }
+
+            // a referenced directory may be a project without a tsconfig of its own
+            if let Ok(canonical_reference) = reference.canonicalize() {
+                if visited_tsconfigs.insert(canonical_reference) {
+                    pending.push(reference);
+                }
+            }
+        }
+    }
+
+    projects
+}
@@ -0,0 +1,22 @@
+/// Dev tools used through their CLIs or configs rather than imports.
+pub const COMMON_DEVTOOLS: [&str; 14] = [
+    "@changesets/cli",
+    "@commitlint/cli",
+    "concurrently",
+    "cross-env",
+    "eslint",
+    "husky",
+    "lint-staged",
+    "nodemon",
+    "npm-run-all",
+    "prettier",
+    "rimraf",
+    "stylelint",
+    "ts-node",
+    "typescript",
+];
+
+/// Check if a dependency is a common dev tool.
+pub fn is_common_devtool(dependency: &str) -> bool {
+    COMMON_DEVTOOLS.contains(&dependency)
+}
@@ -0,0 +1,94 @@
+/// Get packages a shorthand name of a tool plugin may refer to, by the naming convention
+/// shared by babel and eslint, only the babel special expands shorthands so far.
+///
+/// `scope` is the default scope of the tool like `@babel` and `prefix` is the kind of plugin
+/// like `preset`. So `env` may be `@babel/preset-env`, `babel-preset-env` or `env` itself,
+/// `@babel/env` is `@babel/preset-env`, while names of other scopes keep the tool name like
+/// `@org/env` for `@org/babel-preset-env` and a bare scope `@org` for `@org/babel-preset`.
+/// Local paths aren't packages.
+pub fn expand_shorthand(name: &str, scope: &str, prefix: &str) -> Vec<String> {
+    if name.starts_with('.') || name.starts_with('/') {
+        return Vec::new();
+    }
+
+    let tool = scope.trim_start_matches('@');
+
+    if let Some(scoped_name) = name.strip_prefix('@') {
+        let (name_scope, short_name) = match scoped_name.split_once('/') {
+            Some(scoped_name) => scoped_name,
+            None => return vec![format!("{name}/{tool}-{prefix}")],
+        };
+
+        // only packages of the tool scope drop the tool name
+        let scoped_prefix = if name_scope == tool {
+            prefix.to_owned()
+        } else {
+            format!("{tool}-{prefix}")
+        };
+
+        if short_name == scoped_prefix || short_name.starts_with(&format!("{scoped_prefix}-")) {
+            return vec![name.to_owned()];
+        }
+
+        return vec![
+            format!("@{name_scope}/{scoped_prefix}-{short_name}"),
+            name.to_owned(),
+        ];
+    }
+
+    if name.starts_with(&format!("{tool}-{prefix}-")) {
+        return vec![name.to_owned()];
+    }
+
+    vec![
+        format!("{scope}/{prefix}-{name}"),
+        format!("{tool}-{prefix}-{name}"),
+        name.to_owned(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand_shorthand;
+
+    fn expand(name: &str) -> Vec<String> {
+        expand_shorthand(name, "@babel", "preset")
+    }
+
+    #[test]
+    fn test_bare_name() {
+        assert_eq!(
+            expand("env"),
+            ["@babel/preset-env", "babel-preset-env", "env"]
+        );
+    }
+
+    #[test]
+    fn test_tool_scope() {
+        assert_eq!(expand("@babel/env"), ["@babel/preset-env", "@babel/env"]);
+        assert_eq!(expand("@babel/preset-env"), ["@babel/preset-env"]);
+    }
+
+    #[test]
+    fn test_bare_scope() {
+        assert_eq!(expand("@org"), ["@org/babel-preset"]);
+    }
+
+    #[test]
+    fn test_other_scope() {
+        assert_eq!(expand("@org/env"), ["@org/babel-preset-env", "@org/env"]);
+        assert_eq!(expand("@org/babel-preset-env"), ["@org/babel-preset-env"]);
+        assert_eq!(expand("@org/babel-preset"), ["@org/babel-preset"]);
+    }
+
+    #[test]
+    fn test_prefixed_name() {
+        assert_eq!(expand("babel-preset-env"), ["babel-preset-env"]);
+    }
+
+    #[test]
+    fn test_local_name() {
+        assert!(expand("./local-preset").is_empty());
+        assert!(expand("/absolute/preset").is_empty());
+    }
+}
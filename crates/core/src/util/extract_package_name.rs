@@ -0,0 +1,41 @@
+use regex::Regex;
+
+/// Prefixes of Deno and Bun specifiers naming a registry package like `npm:pkg@1.2.3`.
+const REGISTRY_PREFIXES: [&str; 2] = ["npm:", "jsr:"];
+
+/// Get a package name from a module specifier.
+///
+/// Subpaths are dropped whatever their extensions are, so `pkg/foo.js` is `pkg` and
+/// `@scope/pkg/foo.mjs` is `@scope/pkg`, while dots in package names like `normalize.css` are kept.
+/// Registry prefixes and their versions are dropped as well, so `npm:pkg@1.2.3` is `pkg`
+/// and `jsr:@scope/pkg` is `@scope/pkg`.
+pub fn extract_package_name(specifier: &str) -> Option<String> {
+    let registry_specifier = REGISTRY_PREFIXES
+        .iter()
+        .find_map(|prefix| specifier.strip_prefix(prefix));
+    let specifier = registry_specifier.unwrap_or(specifier);
+
+    // only prefixed specifiers may pin versions
+    let strip_version = |name: &str| match registry_specifier {
+        Some(_) => name.split('@').next().unwrap_or(name).to_owned(),
+        None => name.to_owned(),
+    };
+
+    let scope_pattern = Regex::new(r"^(?:(@[^/]+)[/]+)([^/]+)[/]?").unwrap();
+    let base_pattern = Regex::new(r"^([^/]+)[/]?").unwrap();
+    let scope_pattern_test = Regex::new(r"^@").unwrap();
+
+    if scope_pattern_test.is_match(specifier) {
+        let captures = scope_pattern.captures(specifier)?;
+
+        match (captures.get(1), captures.get(2)) {
+            (Some(first), Some(second)) => {
+                Some(first.as_str().to_owned() + "/" + &strip_version(second.as_str()))
+            }
+            _ => None,
+        }
+    } else {
+        let captures = base_pattern.captures(specifier)?;
+        captures.get(1).map(|v| strip_version(v.as_str()))
+    }
+}
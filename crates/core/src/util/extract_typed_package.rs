@@ -0,0 +1,9 @@
+/// Get a package typed by a DefinitelyTyped dependency, `@types/scope__pkg` is `@scope/pkg`.
+pub fn extract_typed_package(type_dependency: &str) -> Option<String> {
+    let name = type_dependency.strip_prefix("@types/")?;
+
+    match name.split_once("__") {
+        Some((scope, name)) => Some(format!("@{scope}/{name}")),
+        None => Some(name.to_owned()),
+    }
+}
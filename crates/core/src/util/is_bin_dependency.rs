@@ -1,13 +1,53 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use crate::util::load_module::load_module;
 
-/// Check if a dependency is a bin dependency for a directory.
-pub fn is_bin_dependency(directory: &Path, dependency: &str) -> bool {
-    let dependency_module = load_module(&directory.join("node_modules").join(dependency));
+/// Get names of bins of an installed dependency, `None` if it has no `bin` field.
+fn load_bin_names(directory: &Path, dependency: &str) -> Option<Vec<String>> {
+    load_module(&directory.join("node_modules").join(dependency))
+        .ok()?
+        .bin
+        .map(|bin| bin.into_keys().collect())
+}
+
+/// Memoized bins of installed dependencies of a directory, so the manifest of each installed
+/// package is read at most once per check.
+#[derive(Debug)]
+pub struct BinDependencies {
+    directory: PathBuf,
+    /// Key is dependency and value is its bin names, `None` if it has no `bin` field.
+    cache: Mutex<HashMap<String, Option<Vec<String>>>>,
+}
+
+impl BinDependencies {
+    pub fn new(directory: &Path) -> Self {
+        BinDependencies {
+            directory: directory.to_path_buf(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Check if a dependency is a bin dependency for the directory.
+    pub fn contains(&self, dependency: &str) -> bool {
+        self.get_bin_names(dependency).is_some()
+    }
+
+    /// Get names of bins of an installed dependency, `None` if it has no `bin` field.
+    pub fn get_bin_names(&self, dependency: &str) -> Option<Vec<String>> {
+        if let Some(bin_names) = self.cache.lock().unwrap().get(dependency) {
+            return bin_names.clone();
+        }
+
+        // the manifest is read without the lock, so parallel walkers don't wait for each other
+        let bin_names = load_bin_names(&self.directory, dependency);
 
-    match dependency_module {
-        Ok(dependency_module) => dependency_module.bin.is_some(),
-        Err(_) => false,
+        self.cache
+            .lock()
+            .unwrap()
+            .entry(dependency.to_owned())
+            .or_insert(bin_names)
+            .clone()
     }
 }
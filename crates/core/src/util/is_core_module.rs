@@ -53,7 +53,7 @@ const CORE_MODULES: [&str; 52] = [
     "zlib",
 ];
 
-/// Check if a module is core node module.
+/// Check if a module is core node module, any `node:` prefixed module like `node:test` is.
 pub fn is_core_module(module: &str) -> bool {
-    CORE_MODULES.contains(&module)
+    module.starts_with("node:") || CORE_MODULES.contains(&module)
 }
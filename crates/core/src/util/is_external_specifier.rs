@@ -0,0 +1,20 @@
+use std::path::{Component, PathBuf};
+
+/// Schemes of URL specifiers imported by Deno or browsers rather than resolved as packages.
+const URL_SCHEMES: [&str; 5] = ["http:", "https:", "data:", "blob:", "file:"];
+
+/// Check if a specifier refers to an external module rather than a relative or absolute path
+/// or a URL like `https://esm.sh/pkg`.
+pub fn is_external_specifier(specifier: &str) -> bool {
+    if specifier.contains("://")
+        || URL_SCHEMES
+            .iter()
+            .any(|scheme| specifier.starts_with(scheme))
+    {
+        return false;
+    }
+
+    let path = PathBuf::from(specifier);
+    let root_component = path.components().next();
+    matches!(root_component, Some(Component::Normal(_)))
+}
@@ -0,0 +1,23 @@
+/// Wildcards of a version range segment like `*` or `x` of `1.x`.
+const WILDCARDS: [&str; 3] = ["*", "x", "X"];
+
+/// Check if a declared version like `*`, `latest`, `x` or an empty range accepts any
+/// release, so a new major version is installed without changes of `package.json`.
+///
+/// Ranges of `npm:` aliases like `npm:other@*` are checked after the aliased name.
+pub fn is_loose_version(version: &str) -> bool {
+    let version = version.trim();
+
+    let range = match version.strip_prefix("npm:") {
+        // a scope of the aliased name starts with `@` as well
+        Some(alias) => alias
+            .get(1..)
+            .and_then(|alias| alias.rsplit_once('@'))
+            .map_or("", |(_, range)| range.trim()),
+        None => version,
+    };
+
+    range.is_empty()
+        || range == "latest"
+        || range.split('.').all(|segment| WILDCARDS.contains(&segment))
+}
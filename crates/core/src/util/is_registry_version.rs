@@ -0,0 +1,32 @@
+/// Prefixes of versions installing a package from outside of the registry.
+const NON_REGISTRY_PREFIXES: [&str; 13] = [
+    "file:",
+    "link:",
+    "portal:",
+    "workspace:",
+    "git:",
+    "git+",
+    "github:",
+    "gitlab:",
+    "bitbucket:",
+    "gist:",
+    "http:",
+    "https:",
+    ".",
+];
+
+/// Check if a declared version like `^1.0.0` or `npm:other@^1.0.0` is resolved by the registry
+/// rather than by a path, a git repository, a tarball url or a `user/repo` GitHub shorthand.
+pub fn is_registry_version(version: &str) -> bool {
+    let version = version.trim();
+
+    if NON_REGISTRY_PREFIXES
+        .iter()
+        .any(|prefix| version.starts_with(prefix))
+    {
+        return false;
+    }
+
+    // `npm:` aliases and dist tags never contain slashes except of a scope of an alias
+    version.starts_with("npm:") || !version.contains('/')
+}
@@ -0,0 +1,17 @@
+use std::path::Path;
+
+/// Check if a dependency of one of scopes like `@org` isn't installed in `node_modules`
+/// of a directory, like a workspace package which isn't built yet.
+pub fn is_unresolved_internal(directory: &Path, scopes: &[String], dependency: &str) -> bool {
+    let scope = match dependency.split_once('/') {
+        Some((scope, _)) if scope.starts_with('@') => scope,
+        _ => return false,
+    };
+
+    // scopes may be given without the leading `@`
+    let is_internal = scopes.iter().any(|internal_scope| {
+        internal_scope.trim_start_matches('@') == scope.trim_start_matches('@')
+    });
+
+    is_internal && !directory.join("node_modules").join(dependency).is_dir()
+}
@@ -0,0 +1,20 @@
+use crate::baseline::Baseline;
+use crate::error::DepcheckError;
+use std::path::Path;
+
+/// The baseline file name.
+pub const BASELINE_FILE: &str = ".depcheck-baseline.json";
+
+/// Get a baseline json from a path, a missing baseline suppresses nothing.
+///
+/// A baseline which exists but fails to read or parse is an error, so a typo doesn't
+/// silently drop every suppression.
+pub fn load_baseline(path: &Path) -> Result<Baseline, DepcheckError> {
+    let baseline_path = path.join(BASELINE_FILE);
+
+    if !baseline_path.is_file() {
+        return Ok(Baseline::default());
+    }
+
+    Baseline::from_path(baseline_path)
+}
@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Get files of a directory tracked by git, joined to the directory.
+///
+/// Returns `None` when the directory isn't inside of a git repository or git isn't installed.
+pub fn load_git_tracked_files(directory: &Path) -> Option<HashSet<PathBuf>> {
+    let output = Command::new("git")
+        .args(["ls-files", "-z"])
+        .current_dir(directory)
+        .output()
+        .map_err(|error| log::warn!("failed to run git ls-files: {:#?}", error))
+        .ok()?;
+
+    if !output.status.success() {
+        log::warn!(
+            "failed to list git tracked files of {:#?}: {}",
+            directory,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return None;
+    }
+
+    let files = String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|file| !file.is_empty())
+        .map(|file| directory.join(file))
+        .collect();
+
+    Some(files)
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::util::load_module::load_module;
+
+/// Get dependencies declared by workspace packages of hoisted scopes.
+///
+/// A scope is a workspace package directory like `packages/ui` or, like in `workspaces`,
+/// a directory of workspace packages like `packages/*`. Scopes matching no directories and
+/// directories without a `package.json` are skipped, the returned warnings describe them.
+pub fn load_hoisted_dependencies(
+    directory: &Path,
+    hoisted_scopes: &[String],
+) -> (HashSet<String>, Vec<String>) {
+    let mut warnings = Vec::new();
+
+    let package_directories = hoisted_scopes
+        .iter()
+        .flat_map(|scope| {
+            let package_directories = match scope.strip_suffix("/*") {
+                Some(parent) => get_subdirectories(&directory.join(parent)),
+                None => vec![directory.join(scope)],
+            }
+            .into_iter()
+            .filter(|path| path.is_dir())
+            .collect::<Vec<_>>();
+
+            if package_directories.is_empty() {
+                let warning = format!("hoisted scope {} matches no directories", scope);
+                log::warn!("{}", warning);
+                warnings.push(warning);
+            }
+
+            package_directories
+        })
+        .collect::<Vec<_>>();
+
+    let dependencies = package_directories
+        .into_iter()
+        .filter_map(|package_directory| match load_module(&package_directory) {
+            Ok(package) => Some(package),
+            Err(error) => {
+                log::warn!("{}", error);
+                warnings.push(format!(
+                    "failed to read package json of hoisted scope {}",
+                    package_directory
+                        .strip_prefix(directory)
+                        .unwrap_or(&package_directory)
+                        .display()
+                ));
+                None
+            }
+        })
+        .flat_map(|package| {
+            package
+                .dependencies
+                .into_keys()
+                .chain(package.dev_dependencies.into_keys())
+                .chain(package.peer_dependencies.into_keys())
+                .chain(package.optional_dependencies.into_keys())
+        })
+        .collect();
+
+    (dependencies, warnings)
+}
+
+/// Get sorted subdirectories, so warnings are reported in a stable order.
+fn get_subdirectories(directory: &Path) -> Vec<PathBuf> {
+    let mut subdirectories = fs::read_dir(directory)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    subdirectories.sort();
+    subdirectories
+}
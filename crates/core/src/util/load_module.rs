@@ -1,8 +1,9 @@
+use crate::error::DepcheckError;
 use crate::package::Package;
 use std::path::Path;
 
 /// Get a package json from a path.
-pub fn load_module(path: &Path) -> eyre::Result<Package> {
+pub fn load_module(path: &Path) -> Result<Package, DepcheckError> {
     let package_path = path.join("package.json");
     Package::from_path(package_path)
 }
@@ -0,0 +1,9 @@
+use crate::error::DepcheckError;
+use crate::tsconfig::TsConfig;
+use std::path::Path;
+
+/// Get a tsconfig json from a path.
+pub fn load_tsconfig(path: &Path) -> Result<TsConfig, DepcheckError> {
+    let tsconfig_path = path.join("tsconfig.json");
+    TsConfig::from_path(tsconfig_path)
+}
@@ -1,5 +1,20 @@
+pub(crate) mod collect_installs;
+pub(crate) mod collect_project_references;
+pub(crate) mod common_devtools;
+pub(crate) mod expand_shorthand;
+pub(crate) mod extract_package_name;
 pub(crate) mod extract_type_name;
+pub(crate) mod extract_typed_package;
 pub(crate) mod is_bin_dependency;
 pub(crate) mod is_core_module;
+pub(crate) mod is_external_specifier;
+pub(crate) mod is_loose_version;
 pub(crate) mod is_module;
+pub(crate) mod is_registry_version;
+pub(crate) mod is_unresolved_internal;
+pub(crate) mod load_baseline;
+pub(crate) mod load_git_tracked_files;
+pub(crate) mod load_hoisted_dependencies;
 pub(crate) mod load_module;
+pub(crate) mod load_tsconfig;
+pub(crate) mod strip_jsonc;
@@ -0,0 +1,80 @@
+/// Strip comments and trailing commas from a JSONC document so it can be read as a JSON.
+pub fn strip_jsonc(content: &str) -> String {
+    strip_trailing_commas(&strip_comments(content))
+}
+
+fn strip_comments(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut characters = content.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '"' => {
+                result.push(character);
+                push_string(&mut result, &mut characters);
+            }
+            '/' if characters.peek() == Some(&'/') => {
+                for character in characters.by_ref() {
+                    if character == '\n' {
+                        result.push(character);
+                        break;
+                    }
+                }
+            }
+            '/' if characters.peek() == Some(&'*') => {
+                characters.next();
+                let mut previous = None;
+                for character in characters.by_ref() {
+                    if previous == Some('*') && character == '/' {
+                        break;
+                    }
+                    previous = Some(character);
+                }
+            }
+            _ => result.push(character),
+        }
+    }
+
+    result
+}
+
+fn strip_trailing_commas(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut characters = content.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '"' => {
+                result.push(character);
+                push_string(&mut result, &mut characters);
+            }
+            ',' => {
+                let rest = characters
+                    .clone()
+                    .find(|character| !character.is_whitespace());
+                if !matches!(rest, Some('}') | Some(']')) {
+                    result.push(character);
+                }
+            }
+            _ => result.push(character),
+        }
+    }
+
+    result
+}
+
+/// Push a string literal body, including the closing quote, as is.
+fn push_string<I: Iterator<Item = char>>(result: &mut String, characters: &mut I) {
+    while let Some(character) = characters.next() {
+        result.push(character);
+        match character {
+            '\\' => {
+                if let Some(escaped) = characters.next() {
+                    result.push(escaped);
+                }
+            }
+            '"' => break,
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,3 @@
+import closeIcon from '@scope/icons/svg/close.svg';
+
+export const icons = { close: closeIcon };
@@ -0,0 +1,5 @@
+import logoUrl from 'file-icons/logo.svg';
+import heroUrl from 'images-pkg/assets/hero.png';
+import interUrl from '@fontsource/inter/files/inter-latin-400-normal.woff2';
+
+export { logoUrl, heroUrl, interUrl };
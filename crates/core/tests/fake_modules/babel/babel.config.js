@@ -0,0 +1,9 @@
+module.exports = {
+  presets: ['env', ['@babel/preset-react', { runtime: 'automatic' }], '@org'],
+  plugins: ['@babel/transform-runtime', 'module:my-plugin', './local-plugin'],
+  env: {
+    test: {
+      plugins: ['istanbul'],
+    },
+  },
+};
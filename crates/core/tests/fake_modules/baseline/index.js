@@ -0,0 +1,2 @@
+import "missing-dep";
+import "suppressed-missing";
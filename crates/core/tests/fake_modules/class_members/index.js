@@ -0,0 +1,25 @@
+class Service {
+  dep = require('field-dep');
+
+  static shared = require('static-field-dep');
+
+  static {
+    this.loaded = require('static-block-dep');
+  }
+
+  get lazy() {
+    return require('getter-dep');
+  }
+
+  method() {
+    return require('method-dep');
+  }
+}
+
+const handlers = {
+  handle() {
+    return require('shorthand-dep');
+  },
+};
+
+module.exports = { Service, handlers };
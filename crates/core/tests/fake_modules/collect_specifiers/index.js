@@ -0,0 +1,4 @@
+const fs = require('fs');
+const fp = require('lodash/fp');
+const local = require('./lib/util');
+require('undeclared-pkg');
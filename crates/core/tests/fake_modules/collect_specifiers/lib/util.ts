@@ -0,0 +1,3 @@
+import type { Options } from '@scope/pkg/options';
+import { readFile } from 'node:fs/promises';
+import { helper } from './helper';
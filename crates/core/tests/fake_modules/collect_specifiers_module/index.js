@@ -0,0 +1,4 @@
+import lodash from 'lodash';
+
+// top level await is valid only in ES modules
+await lodash.noop();
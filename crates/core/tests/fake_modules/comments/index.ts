@@ -0,0 +1,22 @@
+// @ts-expect-error the package has no types
+import untyped from 'untyped-pkg';
+/* a block comment */ import { block } from 'block-pkg';
+/**
+ * A JSDoc comment.
+ */
+import jsdoc from 'jsdoc-pkg';
+// @ts-ignore
+const legacy = require('legacy-pkg');
+import trailing from 'trailing-pkg'; // a trailing comment
+/* a multiline
+   comment */
+export { reexported } from 'reexport-pkg';
+// import commented from 'commented-pkg';
+/* import blockCommented from 'commented-pkg'; */
+
+export async function load() {
+  // eslint-disable-next-line import/no-unresolved
+  return import(/* webpackChunkName: "dynamic" */ 'dynamic-pkg');
+}
+
+export default [untyped, block, jsdoc, legacy, trailing];
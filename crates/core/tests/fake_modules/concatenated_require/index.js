@@ -0,0 +1,9 @@
+const loadDateLocale = (lang) => require('date-fns/locale/' + lang + '/index.js');
+
+const loadPluralRules = (lang) => import(`@formatjs/intl-pluralrules/locale-data/${lang}`);
+
+// neither a relative path nor a partial package name is attributed
+const loadMessages = (lang) => require('./locales/' + lang);
+const loadHighlight = (language) => require('highlight' + language);
+
+module.exports = { loadDateLocale, loadPluralRules, loadMessages, loadHighlight };
@@ -0,0 +1,9 @@
+import { createRequire } from 'module';
+import * as nodeModule from 'node:module';
+
+const load = createRequire(import.meta.url);
+const lodash = load('lodash/fp');
+
+const config = nodeModule.createRequire(import.meta.url)('chalk');
+
+export { lodash, config };
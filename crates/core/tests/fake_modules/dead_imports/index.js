@@ -0,0 +1,11 @@
+import used from 'used-pkg';
+import unused from 'unused-pkg';
+import { first, second } from 'partial-pkg';
+import * as namespace from 'namespace-pkg';
+import 'side-effect-pkg';
+import local from './local';
+
+const object = { unused: 1, second: 2 };
+
+used(local, object.unused);
+console.log(first, namespace.value);
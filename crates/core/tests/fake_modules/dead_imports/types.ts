@@ -0,0 +1,4 @@
+import type { Foo } from 'type-pkg';
+import { Bar } from 'unused-type-pkg';
+
+export const value: Foo = { prop: 'here' };
@@ -0,0 +1 @@
+import { useState } from 'react';
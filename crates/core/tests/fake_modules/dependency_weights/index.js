@@ -0,0 +1,3 @@
+import React from 'react';
+import isEqual from 'lodash/isEqual';
+import debounce from 'lodash/debounce';
@@ -0,0 +1,4 @@
+import React from "react";
+import { debounce } from "lodash";
+
+export const App = debounce(() => React.createElement("div"), 100);
@@ -0,0 +1,5 @@
+import { setupServer } from "msw/node";
+import { App } from "./index";
+
+setupServer();
+App();
@@ -0,0 +1,5 @@
+import { storiesOf } from "@storybook/react";
+import { rest } from "msw";
+import React from "react";
+
+storiesOf("App", module).add("default", () => React.createElement("div", { rest }));
@@ -0,0 +1,3 @@
+require('legacy');
+require('lodash');
+require('plugin');
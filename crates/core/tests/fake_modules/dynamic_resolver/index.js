@@ -0,0 +1,3 @@
+export const loadFeature = (flag) => import(`feature-${flag}`);
+
+export const loadLocale = (locale) => require("./locales/" + locale);
@@ -0,0 +1,7 @@
+const staticDep = require("static-dep");
+
+const hidden = eval("require('hidden-dep')");
+
+const load = new Function("name", "return require(name)");
+
+module.exports = { staticDep, hidden, load };
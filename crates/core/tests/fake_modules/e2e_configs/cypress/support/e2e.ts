@@ -0,0 +1 @@
+import 'cypress-axe';
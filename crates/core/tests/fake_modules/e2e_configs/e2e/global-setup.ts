@@ -0,0 +1,3 @@
+import 'dotenv/config';
+
+export default async function globalSetup() {}
@@ -0,0 +1,7 @@
+import { defineConfig } from '@playwright/test';
+
+export default defineConfig({
+  testDir: './e2e',
+  reporter: [['list'], ['allure-playwright', { outputFolder: 'allure-results' }]],
+  globalSetup: './e2e/global-setup.ts',
+});
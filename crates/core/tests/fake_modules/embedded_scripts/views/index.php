@@ -0,0 +1,14 @@
+<html>
+<body>
+  <h1><?php echo $title; ?></h1>
+  <script type="module">
+    import React from 'react';
+  </script>
+  <script>
+    var _ = require('lodash');
+  </script>
+  <script type="text/x-template" id="item">
+    require('template-dep')
+  </script>
+</body>
+</html>
@@ -0,0 +1 @@
+export declare function main(): void;
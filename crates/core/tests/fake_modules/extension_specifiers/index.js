@@ -0,0 +1,9 @@
+import unscopedJs from 'unscoped-js/lib/index.js';
+import unscopedMjs from 'unscoped-mjs/dist/index.mjs';
+import 'unscoped-css/styles.css';
+import unscopedJson from 'unscoped-json/data.json' assert { type: 'json' };
+import scopedJs from '@scope/scoped-js/lib/index.js';
+import scopedMjs from '@scope/scoped-mjs/dist/index.mjs';
+import '@scope/scoped-css/styles.css';
+import scopedJson from '@scope/scoped-json/data.json';
+import 'normalize.css';
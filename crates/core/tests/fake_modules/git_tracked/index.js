@@ -0,0 +1 @@
+require('tracked-dep');
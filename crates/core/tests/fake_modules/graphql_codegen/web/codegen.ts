@@ -0,0 +1,12 @@
+import type { CodegenConfig } from '@graphql-codegen/cli';
+
+const config: CodegenConfig = {
+  schema: 'schema.graphql',
+  generates: {
+    'src/generated.tsx': {
+      plugins: ['typescript-react-apollo'],
+    },
+  },
+};
+
+export default config;
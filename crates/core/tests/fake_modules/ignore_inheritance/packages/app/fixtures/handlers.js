@@ -0,0 +1 @@
+import { rest } from 'msw';
@@ -0,0 +1 @@
+import axios from 'axios';
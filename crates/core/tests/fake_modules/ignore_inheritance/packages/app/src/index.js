@@ -0,0 +1 @@
+import React from 'react';
@@ -0,0 +1 @@
+import lodash from 'lodash';
@@ -0,0 +1 @@
+import { h } from "preact";
@@ -0,0 +1 @@
+require('my-lib');
@@ -0,0 +1,8 @@
+module.exports = {
+  moduleNameMapper: {
+    "\\.(css|less)$": "identity-obj-proxy",
+    "^canvas$": "<rootDir>/node_modules/jest-canvas-mock",
+    "\\.(png|svg)$": ["<rootDir>/__mocks__/file.js", "@scope/file-mock/index.js"],
+    "^@/(.*)$": "<rootDir>/src/$1",
+  },
+};
@@ -0,0 +1,5 @@
+import React, { Suspense } from 'react';
+
+export const Editor = (): JSX.Element => (
+  <Suspense fallback={null}>{React.createElement(React.lazy(() => import('editor-lib')))}</Suspense>
+);
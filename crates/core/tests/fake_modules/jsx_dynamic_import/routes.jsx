@@ -0,0 +1,7 @@
+import { Route } from 'react-router-dom';
+
+export const routes = (
+  <Route path="/" loader={() => import('some-pkg')}>
+    {() => import('chart-lib')}
+  </Route>
+);
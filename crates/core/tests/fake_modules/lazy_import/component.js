@@ -0,0 +1,3 @@
+import { defineAsyncComponent } from 'vue';
+
+export default defineAsyncComponent(async () => import('async-pkg'));
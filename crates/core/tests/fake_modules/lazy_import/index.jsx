@@ -0,0 +1,15 @@
+import React from 'react';
+import loadable from '@loadable/component';
+
+const Heavy = React.lazy(() => import('heavy-pkg'));
+
+const Loadable = loadable(() => {
+  return import('loadable-pkg');
+});
+
+export const App = () => (
+  <React.Suspense fallback={null}>
+    <Heavy />
+    <Loadable />
+  </React.Suspense>
+);
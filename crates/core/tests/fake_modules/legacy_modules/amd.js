@@ -0,0 +1,3 @@
+define(['require', 'exports', 'jquery', 'backbone/router', './view', 'text!./template.html'], function (require, exports, $, Router) {
+  require(['underscore'], function (_) {});
+});
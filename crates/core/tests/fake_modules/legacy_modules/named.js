@@ -0,0 +1,3 @@
+define('app/main', ['jquery'], function ($) {
+  return $;
+});
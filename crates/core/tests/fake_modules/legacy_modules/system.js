@@ -0,0 +1,3 @@
+System.import('chart-lib').then(function (chart) {
+  chart.render();
+});
@@ -0,0 +1,3 @@
+const { pathToFileURL } = require('url');
+
+module.register('tsx/esm', pathToFileURL(__filename));
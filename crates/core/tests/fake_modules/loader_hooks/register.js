@@ -0,0 +1,9 @@
+import * as module from 'node:module';
+import { register as registerHooks } from 'module';
+import { pathToFileURL } from 'node:url';
+
+module.register('ts-node/esm', pathToFileURL('./'));
+registerHooks('@swc-node/register/esm', import.meta.url);
+
+// local hooks aren't packages
+module.register('./hooks.js', import.meta.url);
@@ -0,0 +1,3 @@
+import React from 'react';
+import _ from 'lodash';
+import moment from 'moment';
@@ -0,0 +1 @@
+const axios = require('axios');
@@ -0,0 +1,3 @@
+const express = require('express');
+const _ = require('lodash');
+const pino = require('pino');
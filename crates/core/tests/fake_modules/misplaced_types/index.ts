@@ -0,0 +1,4 @@
+import lodash from "lodash";
+import React from "react";
+
+export { lodash, React };
@@ -0,0 +1,5 @@
+declare module 'unused-dep' {
+  export const version: string;
+}
+
+export const noop = () => {};
@@ -0,0 +1,7 @@
+declare module 'express-serve-static-core' {
+  interface Request {
+    user?: string;
+  }
+}
+
+declare module 'undeclared-pkg';
@@ -0,0 +1,12 @@
+export {};
+
+declare module 'vue' {
+  interface ComponentCustomProperties {
+    $t: (key: string) => string;
+  }
+}
+
+declare module '*.svg' {
+  const content: string;
+  export default content;
+}
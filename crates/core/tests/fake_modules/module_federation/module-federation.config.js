@@ -0,0 +1,4 @@
+module.exports = {
+  name: 'remote',
+  shared: ['lodash', './local'],
+};
@@ -0,0 +1,5 @@
+export * as utils from 'some-pkg';
+export * from 'star-pkg';
+import * as lodash from 'lodash';
+
+export const chunk = lodash.chunk;
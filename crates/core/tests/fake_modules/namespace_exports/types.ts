@@ -0,0 +1,5 @@
+export * as schema from 'ts-namespace-pkg';
+import * as ns from 'ts-import-pkg';
+export type { Options } from 'ts-type-pkg';
+
+export const value = ns.value;
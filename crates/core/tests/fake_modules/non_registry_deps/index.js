@@ -0,0 +1,3 @@
+const foo = require('foo');
+const bar = require('bar');
+const qux = require('qux');
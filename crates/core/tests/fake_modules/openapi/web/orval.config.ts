@@ -0,0 +1,14 @@
+export default {
+  petstore: {
+    input: "./petstore.yaml",
+    output: {
+      target: "./src/petstore.ts",
+      client: "react-query",
+      mock: true,
+    },
+  },
+  store: {
+    input: "./store.yaml",
+    output: "./src/store.ts",
+  },
+};
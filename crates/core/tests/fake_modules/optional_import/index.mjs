@@ -0,0 +1,11 @@
+import chalk from 'chalk';
+
+let optional = null;
+
+try {
+  optional = await import('optional-pkg');
+} catch {
+  console.log(chalk.yellow('optional-pkg is not installed'));
+}
+
+export default optional;
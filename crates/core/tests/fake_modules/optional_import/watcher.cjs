@@ -0,0 +1,9 @@
+let fsevents = null;
+
+try {
+  fsevents = require('fsevents');
+} catch (error) {
+  fsevents = null;
+}
+
+module.exports = fsevents;
@@ -0,0 +1,3 @@
+const React = require('react');
+
+module.exports = React;
@@ -0,0 +1,7 @@
+const presets = ['preset-a'];
+
+module.exports = {
+  plugins: ['plugin-a', ['@scope/plugin-b', { option: true }], './local-plugin', 'not-installed'],
+  presets,
+  other: ['other-pkg'],
+};
@@ -0,0 +1,3 @@
+import { format } from '@fixture/lib';
+
+export default format;
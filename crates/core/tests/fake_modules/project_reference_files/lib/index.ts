@@ -0,0 +1,4 @@
+import { constants } from '@fixture/shared';
+import capitalize from 'lodash/capitalize';
+
+export const format = (text: string) => capitalize(text + constants.suffix);
@@ -0,0 +1,4 @@
+import React from 'react';
+import { format } from '@fixture/lib';
+
+export default { React, format };
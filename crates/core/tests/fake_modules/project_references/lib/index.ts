@@ -0,0 +1,5 @@
+import { constants } from '@fixture/shared';
+import capitalize from 'lodash/capitalize';
+import missing from 'missing-dep';
+
+export const format = (text: string) => capitalize(text + constants.suffix + missing);
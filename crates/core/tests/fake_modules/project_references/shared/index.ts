@@ -0,0 +1 @@
+export const constants = { suffix: '!' };
@@ -0,0 +1 @@
+$('#app').text(map([1, 2], String).join(', '));
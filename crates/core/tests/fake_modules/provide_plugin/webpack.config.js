@@ -0,0 +1,14 @@
+const webpack = require('webpack');
+
+module.exports = {
+  entry: './src/index.js',
+  plugins: [
+    new webpack.ProvidePlugin({
+      $: 'jquery',
+      jQuery: 'jquery',
+      map: ['lodash', 'map'],
+      process: 'process/browser',
+      utils: './src/utils.js',
+    }),
+  ],
+};
@@ -0,0 +1,3 @@
+export { default as debounce } from 'lodash/debounce';
+export * from 'zod';
+export { useState } from 'react';
@@ -0,0 +1,10 @@
+import chalk from 'npm:chalk';
+import express from 'npm:express@4.18.2';
+import { jsx } from 'npm:react@^18.2.0/jsx-runtime';
+import { z } from 'npm:@scope/schema@1.2.3';
+import { join } from 'jsr:@std/path';
+import { assertEquals } from 'jsr:@std/assert@1.0.0/equals';
+import confetti from 'https://esm.sh/canvas-confetti@1.6.0';
+import lodash from 'http://cdn.example.com/lodash.js';
+
+console.log(chalk, express, jsx, z, join, assertEquals, confetti, lodash);
@@ -0,0 +1 @@
+require('dotenv').config();
@@ -0,0 +1,3 @@
+const importPlugin = require('eslint-plugin-import');
+
+module.exports = { plugins: { import: importPlugin } };
@@ -0,0 +1,4 @@
+require('used-dep');
+require('used-dev-dep');
+require('used-peer-dep');
+require('used-optional-dep');
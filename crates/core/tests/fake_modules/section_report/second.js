@@ -0,0 +1 @@
+require('used-dep');
@@ -0,0 +1,4 @@
+#!/bin/sh
+set -e
+./node_modules/.bin/tsc -p tsconfig.json
+echo "unused-tool-like"
@@ -0,0 +1,5 @@
+import { Button } from '@internal/ui/button';
+import { format } from 'internal-utils';
+import debounce from 'lodash/debounce';
+
+export default { Button, format, debounce };
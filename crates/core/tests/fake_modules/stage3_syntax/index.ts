@@ -0,0 +1,14 @@
+import { inject, injectable } from "inversify";
+
+await import("reflect-metadata");
+
+@injectable()
+export class Service {
+  #id = 0;
+
+  constructor(@inject("logger") private readonly logger: Console) {}
+
+  static isService(value: object): boolean {
+    return #id in value;
+  }
+}
@@ -0,0 +1,16 @@
+import { action, observable } from "mobx";
+
+export default class Store {
+  @observable items = [];
+
+  #count = 0;
+
+  @action
+  add(item) {
+    this.items.push(item);
+  }
+
+  static isStore(value) {
+    return #count in value;
+  }
+}
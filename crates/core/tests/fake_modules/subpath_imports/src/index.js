@@ -0,0 +1,6 @@
+import crypto from "#crypto";
+import stream from "#stream";
+import arrow from "#icons/arrow";
+import { helper } from "#internal/helper";
+
+export { crypto, stream, arrow, helper };
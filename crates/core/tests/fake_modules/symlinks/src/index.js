@@ -0,0 +1 @@
+import "inside-dep";
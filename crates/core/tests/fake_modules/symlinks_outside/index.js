@@ -0,0 +1 @@
+import "outside-dep";
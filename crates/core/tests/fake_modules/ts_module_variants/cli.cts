@@ -0,0 +1,5 @@
+import fs = require('fs-extra');
+
+const yargs = require('yargs');
+
+export = { fs, yargs };
@@ -0,0 +1,3 @@
+import chalk from 'chalk';
+
+export const warn = (message: string): string => chalk.yellow(message);
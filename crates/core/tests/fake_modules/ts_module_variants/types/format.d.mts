@@ -0,0 +1,3 @@
+import type { Options } from 'prettier';
+
+export declare function format(source: string, options?: Options): Promise<string>;
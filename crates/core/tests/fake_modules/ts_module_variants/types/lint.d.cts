@@ -0,0 +1,5 @@
+import type { Linter } from 'eslint';
+
+declare function lint(config: Linter.Config): void;
+
+export = lint;
@@ -0,0 +1,5 @@
+import { foo } from 'utils/foo';
+import { Button } from 'components';
+import { config } from 'config';
+import * as React from 'react';
+import missing from 'missing-dep';
@@ -0,0 +1,2 @@
+import { foo } from 'utils/foo';
+import * as React from 'react';
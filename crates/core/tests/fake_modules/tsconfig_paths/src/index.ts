@@ -0,0 +1,7 @@
+import React from 'react';
+import debounce from '@utils/debounce';
+import { faCheck } from '@icons';
+import { Button } from '@components/button';
+import { schema } from '@generated/schema';
+
+export { React, debounce, faCheck, Button, schema };
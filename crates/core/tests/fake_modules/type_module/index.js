@@ -0,0 +1,3 @@
+import { format } from "esm-dep";
+
+export const print = (value) => console.log(format(value));
@@ -0,0 +1,3 @@
+const { default: plugin } = await import("esm-plugin");
+
+plugin.register();
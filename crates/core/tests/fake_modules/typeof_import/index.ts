@@ -0,0 +1,3 @@
+export const debounce: typeof import("lodash").debounce = (fn) => fn;
+
+export const readFile: typeof import("fs").readFile = () => undefined;
@@ -0,0 +1,3 @@
+export type Component = import("@scope/typed").Component;
+
+export type Props = import("@scope/typed/props").Props<string>;
@@ -0,0 +1,7 @@
+import { readFile } from 'node:fs/promises';
+import test from 'node:test';
+import * as ts from 'typescript';
+
+test('reads a file', async () => {
+  ts.sys.write(await readFile('index.ts', 'utf8'));
+});
@@ -0,0 +1 @@
+const { join } = require('node:path');
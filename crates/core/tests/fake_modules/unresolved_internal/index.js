@@ -0,0 +1,4 @@
+import lodash from 'lodash';
+import { Button } from '@my-org/ui';
+import { format } from '@my-org/utils';
+import { track } from '@other-org/analytics';
@@ -0,0 +1,3 @@
+const { Linter } = require('eslint');
+
+module.exports = { Linter };
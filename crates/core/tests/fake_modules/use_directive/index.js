@@ -0,0 +1,4 @@
+// depcheck-use: some-pkg, other-pkg/feature, fs, ./local
+const name = process.env.PLUGIN;
+
+module.exports = require(name);
@@ -0,0 +1,6 @@
+/**
+ * Plugins are loaded by name at runtime.
+ *
+ * depcheck-use: plugin-x, undeclared-pkg
+ */
+export const plugins = ["plugin-x"];
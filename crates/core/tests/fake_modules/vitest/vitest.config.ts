@@ -0,0 +1,12 @@
+import { defineConfig } from 'vitest/config';
+
+export default defineConfig({
+  test: {
+    environment: 'jsdom',
+    setupFiles: ['./test/setup.ts'],
+    reporters: ['default', ['vitest-sonar-reporter', { outputFile: 'sonar-report.xml' }]],
+    coverage: {
+      provider: 'v8',
+    },
+  },
+});
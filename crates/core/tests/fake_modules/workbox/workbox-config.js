@@ -0,0 +1,19 @@
+module.exports = {
+  globDirectory: 'dist/',
+  globPatterns: ['**/*.{js,css,html}'],
+  swSrc: 'build/sw.js',
+  swDest: 'dist/sw.js',
+  importScripts: ['workbox-sw', 'https://cdn.example.com/analytics.js'],
+  runtimeCaching: [
+    {
+      urlPattern: /\.(?:png|jpg)$/,
+      handler: 'CacheFirst',
+      options: {
+        cacheName: 'images',
+        expiration: {
+          maxEntries: 10,
+        },
+      },
+    },
+  ],
+};
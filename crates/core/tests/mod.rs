@@ -1,11 +1,26 @@
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::env;
-use std::path::PathBuf;
-
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use depckeck_rs_core::baseline::{Baseline, BaselineEntry};
 use depckeck_rs_core::checker::Checker;
-use depckeck_rs_core::checker_result::CheckerResult;
-use depckeck_rs_core::config::Config;
+use depckeck_rs_core::checker_result::{
+    to_dot, to_text, CheckerResult, DependencyWeight, DynamicUsage, ExportsIssue, ExportsIssueKind,
+    OrphanTypeReason, Report, SectionReport, SectionUsage, SpecialReport, Summary,
+};
+use depckeck_rs_core::config::{Config, DependencySource, PathBase};
+use depckeck_rs_core::error::DepcheckError;
+use depckeck_rs_core::parser::{Parser, SyntaxFeatures};
 use pretty_assertions::assert_eq;
+use relative_path::RelativePathBuf;
+use swc_ecma_parser::Syntax;
 
 #[derive(Default)]
 struct ExpectedCheckResult {
@@ -366,6 +381,69 @@ fn test_good() {
     assert_result(actual, expected);
 }
 
+#[cfg(feature = "stream")]
+#[test]
+fn test_check_stream() {
+    init();
+    let path = get_module_path("good");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let stream = checker.check_stream().unwrap();
+    // files without dependencies are yielded as well
+    let actual = futures_executor::block_on_stream(stream)
+        .filter(|(_, dependencies)| !dependencies.is_empty())
+        .collect::<BTreeMap<_, _>>();
+
+    let expected = BTreeMap::from([(
+        RelativePathBuf::from("index.js"),
+        HashSet::from([String::from("foo"), String::from("optimist")]),
+    )]);
+
+    assert_eq!(actual, expected);
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn test_ffi_check() {
+    use depckeck_rs_core::ffi::{
+        depcheck_check, depcheck_free, DEPCHECK_CHECK_FAILED, DEPCHECK_INVALID_ARGUMENT,
+        DEPCHECK_OK,
+    };
+    use std::ffi::{CStr, CString};
+    use std::ptr;
+
+    init();
+    let path = get_module_path("good");
+    let dir = CString::new(path.to_str().unwrap()).unwrap();
+    let mut json = ptr::null_mut();
+
+    let code = unsafe { depcheck_check(dir.as_ptr(), &mut json) };
+    assert_eq!(code, DEPCHECK_OK);
+
+    let report = unsafe { CStr::from_ptr(json) }.to_str().unwrap();
+    let value = serde_json::from_str::<serde_json::Value>(report).unwrap();
+    let actual = serde_json::from_str::<Report>(report).unwrap();
+    unsafe { depcheck_free(json) };
+
+    // the report has the shape of `Report`, not of the raw checker result
+    let keys = value.as_object().unwrap().keys().collect::<Vec<_>>();
+    assert_eq!(keys, ["missing", "summary", "unused", "unusedDev", "using"]);
+
+    let expected = Checker::new(Config::new(path)).check_package().unwrap();
+    assert_eq!(actual, expected.report());
+    assert!(actual.missing.is_empty());
+    assert!(actual.using.contains_key("optimist"));
+
+    let missing = CString::new("missing/directory").unwrap();
+    let code = unsafe { depcheck_check(missing.as_ptr(), &mut json) };
+    assert_eq!(code, DEPCHECK_CHECK_FAILED);
+    assert!(json.is_null());
+
+    let code = unsafe { depcheck_check(ptr::null(), &mut json) };
+    assert_eq!(code, DEPCHECK_INVALID_ARGUMENT);
+}
+
 #[test]
 fn test_good_es6() {
     init();
@@ -1307,3 +1385,3232 @@ fn test_cjs() {
 
     assert_result(actual, expected);
 }
+
+#[test]
+fn test_tsconfig_base_url() {
+    init();
+    let path = get_module_path("tsconfig_base_url");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("react"), [String::from("src/index.ts")].into()),
+            (
+                String::from("missing-dep"),
+                [String::from("src/index.ts")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("missing-dep"),
+            [String::from("src/index.ts")].into(),
+        )]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_dead_imports() {
+    init();
+    let path = get_module_path("dead_imports");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_dead_imports().unwrap();
+
+    let expected = BTreeMap::from([
+        (
+            RelativePathBuf::from("index.js"),
+            vec![String::from("unused-pkg")],
+        ),
+        (
+            RelativePathBuf::from("types.ts"),
+            vec![String::from("unused-type-pkg")],
+        ),
+    ]);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_tsconfig_jsonc() {
+    init();
+    let path = get_module_path("tsconfig_jsonc");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("react"),
+            [String::from("src/index.ts")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_by_section() {
+    init();
+    let path = get_module_path("section_report");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap().by_section();
+
+    let expected = SectionReport {
+        dependencies: SectionUsage {
+            used: BTreeMap::from([(String::from("used-dep"), 2)]),
+            unused: [String::from("unused-dep")].into(),
+        },
+        dev_dependencies: SectionUsage {
+            used: BTreeMap::from([(String::from("used-dev-dep"), 1)]),
+            unused: [String::from("unused-dev-dep")].into(),
+        },
+        peer_dependencies: SectionUsage {
+            used: BTreeMap::from([(String::from("used-peer-dep"), 1)]),
+            unused: [String::from("unused-peer-dep")].into(),
+        },
+        optional_dependencies: SectionUsage {
+            used: BTreeMap::from([(String::from("used-optional-dep"), 1)]),
+            unused: [String::from("unused-optional-dep")].into(),
+        },
+    };
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_workbox() {
+    init();
+    let path = get_module_path("workbox");
+
+    let config = Config::new(path).with_specials(vec![String::from("workbox")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("workbox-cli"),
+                [String::from("workbox-config.js")].into(),
+            ),
+            (
+                String::from("workbox-expiration"),
+                [String::from("workbox-config.js")].into(),
+            ),
+            (
+                String::from("workbox-precaching"),
+                [String::from("build/sw.js")].into(),
+            ),
+            (
+                String::from("workbox-strategies"),
+                [String::from("workbox-config.js")].into(),
+            ),
+            (
+                String::from("workbox-sw"),
+                [String::from("workbox-config.js")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_workbox_disabled() {
+    init();
+    let path = get_module_path("workbox");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dev_dependencies: [
+            String::from("workbox-cli"),
+            String::from("workbox-expiration"),
+            String::from("workbox-precaching"),
+            String::from("workbox-strategies"),
+            String::from("workbox-sw"),
+            String::from("unused-dep"),
+        ]
+        .into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_include_modules() {
+    init();
+    let path = get_module_path("include_modules");
+
+    let config = Config::new(path).with_include_modules(vec![String::from("linked")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("linked"), [String::from("index.js")].into()),
+            (
+                String::from("used-by-linked"),
+                [String::from("node_modules/linked/index.js")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_graphql_codegen() {
+    init();
+    let path = get_module_path("graphql_codegen");
+
+    let config = Config::new(path).with_specials(vec![String::from("graphql-codegen")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@graphql-codegen/add"),
+                [String::from("codegen.yml")].into(),
+            ),
+            (
+                String::from("@graphql-codegen/cli"),
+                [String::from("codegen.yml"), String::from("web/codegen.ts")].into(),
+            ),
+            (
+                String::from("@graphql-codegen/client-preset"),
+                [String::from("codegen.yml")].into(),
+            ),
+            (
+                String::from("@graphql-codegen/typescript"),
+                [String::from("codegen.yml")].into(),
+            ),
+            (
+                String::from("@graphql-codegen/typescript-operations"),
+                [String::from("codegen.yml")].into(),
+            ),
+            (
+                String::from("@graphql-codegen/typescript-react-apollo"),
+                [String::from("web/codegen.ts")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_import_map() {
+    init();
+    let path = get_module_path("import_map");
+
+    let config = Config::new(path).with_specials(vec![String::from("import-map")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/widgets"),
+                [String::from("importmap.json")].into(),
+            ),
+            (
+                String::from("lit"),
+                [String::from("public/index.html")].into(),
+            ),
+            (
+                String::from("lodash"),
+                [String::from("importmap.json")].into(),
+            ),
+            (
+                String::from("preact"),
+                [
+                    String::from("public/app.js"),
+                    String::from("public/index.html"),
+                ]
+                .into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_report() {
+    init();
+    let path = get_module_path("tsconfig_base_url");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap().report();
+
+    let expected = Report {
+        missing: BTreeMap::from([(
+            String::from("missing-dep"),
+            [String::from("src/index.ts")].into(),
+        )]),
+        unused: [String::from("unused-dep")].into(),
+        using: BTreeMap::from([
+            (
+                String::from("missing-dep"),
+                [String::from("src/index.ts")].into(),
+            ),
+            (String::from("react"), [String::from("src/index.ts")].into()),
+        ]),
+        summary: Summary {
+            missing: 1,
+            unused: 1,
+            unused_dev: 0,
+            files_scanned: 4,
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(actual, expected);
+
+    let json = serde_json::to_value(&actual).unwrap();
+    assert_eq!(
+        json["summary"],
+        serde_json::json!({ "missing": 1, "unused": 1, "unusedDev": 0, "filesScanned": 4 })
+    );
+}
+
+#[test]
+fn test_max_files_per_dependency() {
+    init();
+    let path = get_module_path("package");
+
+    let config = Config::new(path).with_max_files_per_dependency(Some(2));
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap().report();
+
+    // the marker follows the sample files
+    assert_eq!(
+        actual.using["react"],
+        vec![
+            String::from("src/rootFile.ts"),
+            String::from("src/subDir/subDirFile.ts"),
+            String::from("...1 more"),
+        ]
+    );
+    assert_eq!(
+        actual.using["@packageRoot/first1"],
+        vec![String::from("src/rootFile.ts")]
+    );
+    assert_eq!(
+        actual.missing["react"].last().map(String::as_str),
+        Some("...1 more")
+    );
+
+    let json = serde_json::to_value(&actual).unwrap();
+    assert_eq!(
+        json["using"]["react"],
+        serde_json::json!(["src/rootFile.ts", "src/subDir/subDirFile.ts", "...1 more"])
+    );
+}
+
+#[test]
+fn test_to_dot() {
+    let mut result = CheckerResult::default();
+    result.using_dependencies = BTreeMap::from([
+        (
+            String::from("react"),
+            [String::from("src/b.js"), String::from("src/a.js")].into(),
+        ),
+        (
+            String::from("weird\"pkg"),
+            [String::from("src\\a.js"), String::from("src/a.js")].into(),
+        ),
+    ]);
+
+    let expected = [
+        "digraph dependencies {",
+        r#"    "dependency:react" [label="react", shape=box];"#,
+        r#"    "dependency:weird\"pkg" [label="weird\"pkg", shape=box];"#,
+        r#"    "file:src/a.js" [label="src/a.js"];"#,
+        r#"    "file:src/b.js" [label="src/b.js"];"#,
+        r#"    "file:src\\a.js" [label="src\\a.js"];"#,
+        r#"    "dependency:react" -> "file:src/a.js" [label="used-by"];"#,
+        r#"    "dependency:react" -> "file:src/b.js" [label="used-by"];"#,
+        r#"    "dependency:weird\"pkg" -> "file:src/a.js" [label="used-by"];"#,
+        r#"    "dependency:weird\"pkg" -> "file:src\\a.js" [label="used-by"];"#,
+        "}",
+        "",
+    ]
+    .join("\n");
+
+    assert_eq!(to_dot(&result), expected);
+}
+
+#[test]
+fn test_extension_specifiers() {
+    init();
+    let path = get_module_path("extension_specifiers");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/scoped-css"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("@scope/scoped-js"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("@scope/scoped-json"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("@scope/scoped-mjs"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("normalize.css"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("unscoped-css"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("unscoped-js"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("unscoped-json"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("unscoped-mjs"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_single_threaded() {
+    init();
+    let path = get_module_path("include_modules");
+    let config = Config::new(path).with_include_modules(vec![String::from("linked")]);
+
+    let parallel = Checker::new(config.clone()).check_package().unwrap();
+    let sequential = Checker::new(config.with_single_threaded(true))
+        .check_package()
+        .unwrap();
+
+    assert_eq!(sequential, parallel);
+}
+
+#[test]
+fn test_follow_symlinks() {
+    init();
+    let path = get_module_path("symlinks");
+
+    let config = Config::new(path).with_follow_symlinks(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("inside-dep"),
+            [String::from("alias/index.js"), String::from("src/index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("outside-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_typeof_import() {
+    init();
+    let path = get_module_path("typeof_import");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/typed"),
+                [String::from("types.ts")].into(),
+            ),
+            (
+                String::from("@types/lodash"),
+                [String::from("index.ts")].into(),
+            ),
+            (
+                String::from("@types/node"),
+                [String::from("index.ts")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_missing_entry_files() {
+    init();
+    let path = get_module_path("entry_files");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(
+        actual.get_missing_entry_files(),
+        vec![
+            (String::from("types"), PathBuf::from("lib/index.d.ts")),
+            (
+                String::from("bin.missing-cli"),
+                PathBuf::from("bin/missing.js")
+            ),
+            (
+                String::from("exports[\"./feature\"].default[0]"),
+                PathBuf::from("./lib/feature.js")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_baseline() {
+    init();
+    let path = get_module_path("baseline");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(
+        actual.get_expired_baseline(),
+        &Baseline {
+            unused: vec![BaselineEntry {
+                name: String::from("expired-unused"),
+                until: Some(String::from("2000-01-01")),
+            }],
+            ..Default::default()
+        }
+    );
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("missing-dep"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("suppressed-missing"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("missing-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("expired-unused"), String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_malformed_baseline() {
+    init();
+    let path = get_module_path("baseline_malformed");
+
+    let config = Config::new(path);
+    let result = Checker::new(config).check_package();
+    assert!(matches!(result, Err(DepcheckError::ParseManifest { .. })));
+
+    // the deserializer error is kept as the source with its position
+    let source = result.unwrap_err().source().and_then(|source| {
+        source
+            .downcast_ref::<serde_json::Error>()
+            .map(|error| error.line())
+    });
+    assert_eq!(source, Some(3));
+}
+
+#[test]
+fn test_baseline_dates() {
+    let baseline =
+        Baseline::from_slice(br#"{ "unused": [{ "name": "a", "until": "2026-09-01" }] }"#).unwrap();
+    assert!(baseline.unused[0].is_expired("2026-10-14"));
+    assert!(!baseline.unused[0].is_expired("2026-09-01"));
+
+    // unpadded dates would never expire when compared as strings
+    for until in [
+        "2026-9-1",
+        "2026-13-01",
+        "2026-02-30",
+        "26-09-01",
+        "2026-+9-01",
+    ] {
+        let json = format!(r#"{{ "unused": [{{ "name": "a", "until": "{until}" }}] }}"#);
+        assert!(Baseline::from_slice(json.as_bytes()).is_err(), "{}", until);
+    }
+
+    assert!(
+        Baseline::from_slice(br#"{ "unused": [{ "name": "a", "until": "2024-02-29" }] }"#).is_ok()
+    );
+}
+
+#[test]
+fn test_dynamic_usage() {
+    init();
+    let path = get_module_path("dynamic_usage");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(
+        actual.get_dynamic_usage(),
+        [
+            DynamicUsage {
+                file: String::from("index.js"),
+                source: String::from("eval(\"require('hidden-dep')\")"),
+            },
+            DynamicUsage {
+                file: String::from("index.js"),
+                source: String::from("new Function(\"name\", \"return require(name)\")"),
+            },
+        ]
+    );
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("static-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("hidden-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_misplaced_type_packages() {
+    init();
+    let path = get_module_path("misplaced_types");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(
+        actual.get_misplaced_type_packages(),
+        vec!["@types/lodash", "@types/react"]
+    );
+}
+
+#[test]
+fn test_dynamic_resolver() {
+    init();
+    let path = get_module_path("dynamic_resolver");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config).with_dynamic_resolver(Box::new(|source: &str| {
+        if source == "`feature-${flag}`" {
+            vec![
+                String::from("feature-a"),
+                String::from("feature-b"),
+                String::from("fs"),
+                String::from("./feature-local"),
+            ]
+        } else {
+            Vec::new()
+        }
+    }));
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("feature-a"), [String::from("index.js")].into()),
+            (String::from("feature-b"), [String::from("index.js")].into()),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_checker_eq_ignores_callbacks() {
+    let path = get_module_path("dynamic_resolver");
+
+    let checker = Checker::new(Config::new(path.clone()));
+    let resolved_checker = Checker::new(Config::new(path.clone()))
+        .with_dynamic_resolver(Box::new(|_: &str| Vec::new()));
+
+    assert_eq!(checker, resolved_checker);
+    assert_ne!(
+        checker,
+        Checker::new(Config::new(path).with_scan_hidden(true))
+    );
+}
+
+#[test]
+fn test_specifier_mapper() {
+    init();
+    let path = get_module_path("specifier_mapper");
+
+    let config = Config::new(path);
+    let checker =
+        Checker::new(config).with_specifier_mapper(Box::new(|dependency: &str| match dependency {
+            "@internal/ui" => Some(String::from("@company/ui")),
+            "internal-utils" => None,
+            dependency => Some(dependency.to_owned()),
+        }));
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@company/ui"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("lodash"), [String::from("index.js")].into()),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_css_import_chain() {
+    init();
+    let path = get_module_path("css_import");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/fonts"),
+                [String::from("src/app.css")].into(),
+            ),
+            (
+                String::from("some-pkg"),
+                [String::from("src/app.css")].into(),
+            ),
+            (
+                String::from("tokens"),
+                [String::from("src/theme.css")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_less_imports() {
+    init();
+    let path = get_module_path("less");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/icons"),
+                [String::from("styles/app.less")].into(),
+            ),
+            (
+                String::from("antd"),
+                [String::from("styles/app.less")].into(),
+            ),
+            (
+                String::from("bootstrap"),
+                [String::from("styles/app.less")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_tailwind_directives() {
+    init();
+    let path = get_module_path("tailwind");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@tailwindcss/typography"),
+                [String::from("src/app.css")].into(),
+            ),
+            (
+                String::from("daisyui"),
+                [String::from("src/app.css")].into(),
+            ),
+            (
+                String::from("tailwindcss"),
+                [String::from("src/app.css")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_css_modules_composes() {
+    init();
+    let path = get_module_path("css_modules");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("design-tokens"),
+                [String::from("src/button.module.css")].into(),
+            ),
+            (
+                String::from("ui-kit"),
+                [String::from("src/button.module.css")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_check_packages() {
+    init();
+    let directories = vec![get_module_path("package"), get_module_path("dynamic_usage")];
+
+    let checker = Checker::new(Config::new(PathBuf::from(".")));
+    let actual = checker.check_packages(directories.clone()).unwrap();
+
+    let expected = directories
+        .into_iter()
+        .map(|directory| {
+            let result = Checker::new(Config::new(directory.clone()))
+                .check_package()
+                .unwrap();
+            (directory, result)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_follow_project_references() {
+    init();
+    let path = get_module_path("project_references").join("app");
+
+    let config = Config::new(path.clone()).with_follow_project_references(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_projects().unwrap();
+
+    let projects = actual
+        .iter()
+        .map(|(directory, _)| directory.canonicalize().unwrap())
+        .collect::<Vec<_>>();
+    let project_references = get_module_path("project_references");
+    assert_eq!(projects.len(), 3);
+    assert_eq!(projects[0], project_references.join("app"));
+    assert!(projects.contains(&project_references.join("lib")));
+    assert!(projects.contains(&project_references.join("shared")));
+
+    // packages of referenced projects aren't missing
+    let (_, app) = &actual[0];
+    assert_eq!(
+        app.using_dependencies.keys().collect::<Vec<_>>(),
+        vec!["@fixture/lib", "react"]
+    );
+    assert!(app.missing_dependencies.is_empty());
+
+    let (_, lib) = actual
+        .iter()
+        .find(|(directory, _)| directory.ends_with("lib"))
+        .unwrap();
+    assert_eq!(
+        lib.missing_dependencies.keys().collect::<Vec<_>>(),
+        vec!["missing-dep"]
+    );
+    assert_eq!(lib.unused_dependencies, [String::from("unused-dep")].into());
+
+    let config = Config::new(path);
+    let actual = Checker::new(config).check_projects().unwrap();
+    assert_eq!(actual.len(), 1);
+    assert_eq!(
+        actual[0].1.missing_dependencies.keys().collect::<Vec<_>>(),
+        vec!["@fixture/lib"]
+    );
+}
+
+#[test]
+fn test_follow_project_reference_files() {
+    init();
+    let path = get_module_path("project_reference_files");
+
+    let config = Config::new(path.join("app")).with_follow_project_references(true);
+    let actual = Checker::new(config).check_projects().unwrap();
+
+    // references of `lib/tsconfig.build.json` are followed although `lib` has no tsconfig.json
+    let projects = actual
+        .iter()
+        .map(|(directory, _)| directory.canonicalize().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        projects,
+        vec![path.join("app"), path.join("lib"), path.join("shared")]
+    );
+
+    let (_, lib) = &actual[1];
+    assert!(lib.missing_dependencies.is_empty());
+
+    // `scripts` has no package json and is outside of the checked packages
+    let (_, app) = &actual[0];
+    assert_eq!(
+        app.get_warnings(),
+        [format!(
+            "referenced project {} has no package json, its files aren't checked",
+            path.join("scripts").display()
+        )]
+    );
+}
+
+#[test]
+fn test_installed_package() {
+    init();
+    let path = get_module_path("installed_package")
+        .join("node_modules")
+        .join("my-lib");
+
+    // `node_modules` is ignored by the project, the installed package is checked anyway
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("declared-dep"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("undeclared-dep"),
+                [String::from("lib/util.js")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("undeclared-dep"),
+            [String::from("lib/util.js")].into(),
+        )]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_shell() {
+    init();
+    let path = get_module_path("shell");
+
+    let config = Config::new(path).with_specials(vec![String::from("shell")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("some-tool"),
+                [String::from("Dockerfile")].into(),
+            ),
+            (
+                String::from("typescript"),
+                [String::from("scripts/build.sh")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("library")].into(),
+        unused_dev_dependencies: [String::from("unused-tool")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_type_module() {
+    init();
+    let path = get_module_path("type_module");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("esm-dep"), [String::from("index.js")].into()),
+            (
+                String::from("esm-plugin"),
+                [String::from("plugin.js")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_subpath_imports() {
+    init();
+    let path = get_module_path("subpath_imports");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/icons"),
+                [String::from("src/index.js")].into(),
+            ),
+            (
+                String::from("crypto-browserify"),
+                [String::from("src/index.js")].into(),
+            ),
+            (
+                String::from("stream-browserify"),
+                [String::from("src/index.js")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_syntax_for_path() {
+    let parser = Parser::default();
+
+    assert!(matches!(
+        parser.syntax_for_path(Path::new("src/index.js")),
+        Some(Syntax::Es(config)) if config.jsx
+    ));
+    assert!(matches!(
+        parser.syntax_for_path(Path::new("src/index.ts")),
+        Some(Syntax::Typescript(config)) if !config.tsx
+    ));
+    assert!(matches!(
+        parser.syntax_for_path(Path::new("src/index.tsx")),
+        Some(Syntax::Typescript(config)) if config.tsx
+    ));
+    assert!(parser.syntax_for_path(Path::new("src/index.css")).is_none());
+}
+
+#[test]
+fn test_jest_module_name_mapper() {
+    init();
+    let path = get_module_path("jest");
+
+    let config = Config::new(path).with_specials(vec![String::from("jest")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/file-mock"),
+                [String::from("jest.config.js")].into(),
+            ),
+            (
+                String::from("identity-obj-proxy"),
+                [String::from("jest.config.js")].into(),
+            ),
+            (
+                String::from("jest-canvas-mock"),
+                [String::from("jest.config.js")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("jest")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_jest_package_config() {
+    init();
+    let path = get_module_path("jest_package");
+
+    let config = Config::new(path).with_specials(vec![String::from("jest")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("identity-obj-proxy"),
+            [String::from("package.json")].into(),
+        )]),
+        unused_dev_dependencies: [String::from("jest")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_babel_shorthand() {
+    init();
+    let path = get_module_path("babel");
+
+    let config = Config::new(path).with_specials(vec![String::from("babel")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@babel/plugin-transform-runtime"),
+                [String::from("babel.config.js")].into(),
+            ),
+            (
+                String::from("@babel/preset-env"),
+                [String::from("babel.config.js")].into(),
+            ),
+            (
+                String::from("@babel/preset-react"),
+                [String::from("babel.config.js")].into(),
+            ),
+            (
+                String::from("@org/babel-preset"),
+                [String::from("babel.config.js")].into(),
+            ),
+            (
+                String::from("babel-plugin-istanbul"),
+                [String::from("babel.config.js")].into(),
+            ),
+            (
+                String::from("my-plugin"),
+                [String::from("babel.config.js")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("babel-plugin-unused")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_module_federation() {
+    init();
+    let path = get_module_path("module_federation");
+
+    let config = Config::new(path).with_specials(vec![String::from("module-federation")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("lodash"),
+                [String::from("module-federation.config.js")].into(),
+            ),
+            (
+                String::from("react"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("react-dom"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("webpack"),
+                [String::from("webpack.config.js")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("moment")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_renovate_presets() {
+    init();
+    let path = get_module_path("renovate");
+
+    let config = Config::new(path).with_specials(vec![String::from("renovate")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@acme/renovate-presets"),
+                [String::from("renovate.json")].into(),
+            ),
+            (
+                String::from("@org/renovate-config"),
+                [String::from("renovate.json")].into(),
+            ),
+            (
+                String::from("renovate-config-local"),
+                [String::from("package.json")].into(),
+            ),
+            (
+                String::from("renovate-config-shared"),
+                [String::from("renovate.json")].into(),
+            ),
+            (
+                String::from("renovate-config-team"),
+                [String::from("renovate.json")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("renovate-config-unused")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_suppress_common_devtools() {
+    init();
+    let path = get_module_path("common_devtools");
+
+    let config = Config::new(path).with_suppress_common_devtools(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("typescript")].into(),
+        unused_dev_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_proto() {
+    init();
+    let path = get_module_path("proto");
+
+    let config = Config::new(path).with_specials(vec![String::from("proto")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("google-protobuf"),
+            [String::from("protos/service.proto")].into(),
+        )]),
+        unused_dependencies: [
+            String::from("grpc-gateway-protos"),
+            String::from("unused-dep"),
+        ]
+        .into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_proto_package_map() {
+    init();
+    let path = get_module_path("proto");
+
+    let config = Config::new(path)
+        .with_specials(vec![String::from("proto")])
+        .with_proto_package_map(HashMap::from([(
+            String::from("google/api/"),
+            String::from("grpc-gateway-protos"),
+        )]));
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("grpc-gateway-protos"),
+            [String::from("protos/service.proto")].into(),
+        )]),
+        unused_dependencies: [String::from("google-protobuf"), String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_cancellation() {
+    init();
+    let path = get_module_path("package");
+
+    let cancellation = Arc::new(AtomicBool::new(true));
+
+    let result = Checker::new(Config::new(path.clone()))
+        .with_cancellation(Arc::clone(&cancellation))
+        .check_package();
+    assert!(matches!(result, Err(DepcheckError::Cancelled { .. })));
+
+    let result = Checker::new(Config::new(path).with_single_threaded(true))
+        .with_cancellation(cancellation)
+        .check_package();
+    assert!(matches!(result, Err(DepcheckError::Cancelled { .. })));
+}
+
+#[test]
+fn test_watch() {
+    init();
+    let path = get_module_path("package");
+
+    let cancellation = Arc::new(AtomicBool::new(false));
+    let results = Mutex::new(Vec::new());
+
+    Checker::new(Config::new(path))
+        .with_cancellation(Arc::clone(&cancellation))
+        .watch(|result| {
+            results.lock().unwrap().push(result);
+            cancellation.store(true, Ordering::Relaxed);
+        })
+        .unwrap();
+
+    let results = results.into_inner().unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].unused_dependencies.contains("unusedPackage"));
+}
+
+#[test]
+fn test_watch_changes() {
+    init();
+    let path = get_module_path("watch_changes");
+
+    struct AddedFile(PathBuf);
+
+    impl Drop for AddedFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    let added_file = AddedFile(path.join("feature.js"));
+    let _ = fs::remove_file(&added_file.0);
+
+    let cancellation = Arc::new(AtomicBool::new(false));
+    let results = Mutex::new(Vec::new());
+
+    // watching stops even if the change isn't noticed
+    let timeout_cancellation = Arc::clone(&cancellation);
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(10));
+        timeout_cancellation.store(true, Ordering::Relaxed);
+    });
+
+    Checker::new(Config::new(path))
+        .with_cancellation(Arc::clone(&cancellation))
+        .watch(|result| {
+            let mut results = results.lock().unwrap();
+
+            if results.is_empty() {
+                fs::write(&added_file.0, "require('moment');").unwrap();
+            } else {
+                cancellation.store(true, Ordering::Relaxed);
+            }
+
+            results.push(result);
+        })
+        .unwrap();
+
+    let results = results.into_inner().unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].unused_dependencies.contains("moment"));
+    assert!(results[1].using_dependencies.contains_key("moment"));
+    assert!(results[1].unused_dependencies.is_empty());
+}
+
+#[test]
+fn test_embedded_scripts() {
+    init();
+    let path = get_module_path("embedded_scripts");
+
+    let config = Config::new(path)
+        .with_embedded_script_extensions(vec![String::from("php"), String::from("html.twig")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("lodash"),
+                [String::from("views/index.php")].into(),
+            ),
+            (
+                String::from("moment"),
+                [String::from("views/show.html.twig")].into(),
+            ),
+            (
+                String::from("react"),
+                [String::from("views/index.php")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("template-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_dependency_weights() {
+    init();
+    let path = get_module_path("dependency_weights");
+
+    let checker = Checker::new(Config::new(path));
+    let actual = checker.check_package().unwrap();
+
+    let weight = |files, imports| DependencyWeight { files, imports };
+    let expected = vec![
+        ("unused-dep", weight(0, 0)),
+        ("chai", weight(1, 1)),
+        ("lodash", weight(1, 2)),
+        ("react", weight(2, 2)),
+    ];
+
+    assert_eq!(actual.dependency_weights(), expected);
+}
+
+#[test]
+fn test_lockfile_dependency_source() {
+    init();
+    let path = get_module_path("lockfile");
+
+    let config = Config::new(path.clone()).with_dependency_source(DependencySource::Lockfile(
+        PathBuf::from("package-lock.json"),
+    ));
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("lodash"), [String::from("index.js")].into()),
+            (String::from("moment"), [String::from("index.js")].into()),
+            (String::from("react"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dev_dependencies: [String::from("unused-locked-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+
+    let config = Config::new(path)
+        .with_dependency_source(DependencySource::Lockfile(PathBuf::from("yarn.lock")));
+    let result = Checker::new(config).check_package();
+    assert!(matches!(result, Err(DepcheckError::ParseManifest { .. })));
+}
+
+#[test]
+fn test_lazy_import() {
+    init();
+    let path = get_module_path("lazy_import");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@loadable/component"),
+                [String::from("index.jsx")].into(),
+            ),
+            (
+                String::from("async-pkg"),
+                [String::from("component.js")].into(),
+            ),
+            (
+                String::from("heavy-pkg"),
+                [String::from("index.jsx")].into(),
+            ),
+            (
+                String::from("loadable-pkg"),
+                [String::from("index.jsx")].into(),
+            ),
+            (String::from("react"), [String::from("index.jsx")].into()),
+            (String::from("vue"), [String::from("component.js")].into()),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_collect_specifiers() {
+    init();
+    let path = get_module_path("collect_specifiers");
+
+    let checker = Checker::new(Config::new(path));
+    let actual = checker.collect_specifiers().unwrap();
+
+    let expected = BTreeMap::from([
+        (
+            RelativePathBuf::from("index.js"),
+            BTreeSet::from([
+                String::from("fs"),
+                String::from("lodash"),
+                String::from("undeclared-pkg"),
+            ]),
+        ),
+        (
+            RelativePathBuf::from("lib/util.ts"),
+            BTreeSet::from([String::from("@scope/pkg"), String::from("node:fs")]),
+        ),
+    ]);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_collect_specifiers_es_module() {
+    init();
+    let path = get_module_path("collect_specifiers_module");
+
+    // `.js` files of a `"type": "module"` package are parsed as ES modules
+    let checker = Checker::new(Config::new(path));
+    let actual = checker.collect_specifiers().unwrap();
+
+    let expected = BTreeMap::from([(
+        RelativePathBuf::from("index.js"),
+        BTreeSet::from([String::from("lodash")]),
+    )]);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_typescript_node_prefix() {
+    init();
+    let path = get_module_path("typescript_node_prefix");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@types/node"),
+                [String::from("index.ts")].into(),
+            ),
+            (
+                String::from("typescript"),
+                [String::from("index.ts")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_absolute_path_base() {
+    init();
+    let path = get_module_path("import_function");
+
+    let config = Config::new(path.clone()).with_path_base(PathBase::Absolute);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("optimist"),
+            [path.join("index.js").display().to_string()].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_e2e_configs() {
+    init();
+    let path = get_module_path("e2e_configs");
+
+    let config = Config::new(path)
+        .with_ignore_patterns(vec![String::from("cypress"), String::from("e2e")])
+        .with_specials(vec![String::from("cypress"), String::from("playwright")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@playwright/test"),
+                [String::from("playwright.config.ts")].into(),
+            ),
+            (
+                String::from("allure-playwright"),
+                [String::from("playwright.config.ts")].into(),
+            ),
+            (
+                String::from("cypress"),
+                [String::from("cypress.config.ts")].into(),
+            ),
+            (
+                String::from("cypress-axe"),
+                [String::from("cypress/support/e2e.ts")].into(),
+            ),
+            (
+                String::from("cypress-multi-reporters"),
+                [String::from("cypress.config.ts")].into(),
+            ),
+            (
+                String::from("dotenv"),
+                [String::from("e2e/global-setup.ts")].into(),
+            ),
+            (
+                String::from("mocha-junit-reporter"),
+                [String::from("cypress.config.ts")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_uninstalled_dependencies() {
+    init();
+    let path = get_module_path("uninstalled");
+
+    let checker = Checker::new(Config::new(path));
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(
+        actual.get_uninstalled_dependencies(),
+        vec!["@scope/uninstalled-dev", "uninstalled"]
+    );
+}
+
+#[test]
+fn test_hoisted_scopes() {
+    init();
+    let path = get_module_path("hoisted_scopes");
+
+    let config = Config::new(path.clone()).with_ignore_patterns(vec![String::from("packages")]);
+    let actual = Checker::new(config).check_package().unwrap();
+
+    assert_eq!(
+        actual.unused_dependencies,
+        [
+            String::from("express"),
+            String::from("react"),
+            String::from("unused-dep")
+        ]
+        .into()
+    );
+
+    let config = Config::new(path)
+        .with_ignore_patterns(vec![String::from("packages")])
+        .with_hoisted_scopes(vec![
+            String::from("packages/*"),
+            String::from("tools/scripts"),
+        ]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_manifest_package_fields() {
+    init();
+    let path = get_module_path("manifest_fields");
+
+    let config = Config::new(path).with_manifest_package_fields(vec![
+        String::from("config.commitizen.path"),
+        String::from("release.plugins"),
+    ]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@semantic-release/commit-analyzer"),
+                [String::from("package.json")].into(),
+            ),
+            (
+                String::from("@semantic-release/npm"),
+                [String::from("package.json")].into(),
+            ),
+            (
+                String::from("cz-conventional-changelog"),
+                [String::from("package.json")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_error_kinds() {
+    init();
+
+    let config = Config::new(get_module_path("missing_package_json"));
+    let result = Checker::new(config).check_package();
+    assert!(matches!(result, Err(DepcheckError::Io { .. })));
+
+    let config = Config::new(get_module_path("import_function"))
+        .with_ignore_patterns(vec![String::from("{unclosed")]);
+    let result = Checker::new(config).check_package();
+    assert!(matches!(result, Err(DepcheckError::Pattern { .. })));
+}
+
+#[test]
+fn test_comments() {
+    init();
+    let path = get_module_path("comments");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("block-pkg"), [String::from("index.ts")].into()),
+            (
+                String::from("dynamic-pkg"),
+                [String::from("index.ts")].into(),
+            ),
+            (String::from("jsdoc-pkg"), [String::from("index.ts")].into()),
+            (
+                String::from("legacy-pkg"),
+                [String::from("index.ts")].into(),
+            ),
+            (
+                String::from("reexport-pkg"),
+                [String::from("index.ts")].into(),
+            ),
+            (
+                String::from("trailing-pkg"),
+                [String::from("index.ts")].into(),
+            ),
+            (
+                String::from("untyped-pkg"),
+                [String::from("index.ts")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("commented-pkg")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_stage3_syntax() {
+    init();
+    let path = get_module_path("stage3_syntax");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("inversify"), [String::from("index.ts")].into()),
+            (String::from("mobx"), [String::from("store.js")].into()),
+            (
+                String::from("reflect-metadata"),
+                [String::from("index.ts")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_disabled_syntax_features() {
+    init();
+    let path = get_module_path("stage3_syntax");
+
+    let syntax_features = SyntaxFeatures {
+        decorators: false,
+        decorators_before_export: false,
+        ..Default::default()
+    };
+    let config = Config::new(path).with_syntax_features(syntax_features);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [
+            String::from("inversify"),
+            String::from("mobx"),
+            String::from("reflect-metadata"),
+        ]
+        .into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_openapi() {
+    init();
+    let path = get_module_path("openapi");
+
+    let config = Config::new(path).with_specials(vec![String::from("openapi")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@faker-js/faker"),
+                [String::from("web/orval.config.ts")].into(),
+            ),
+            (
+                String::from("@openapitools/openapi-generator-cli"),
+                [String::from("openapitools.json")].into(),
+            ),
+            (
+                String::from("@tanstack/react-query"),
+                [String::from("web/orval.config.ts")].into(),
+            ),
+            (
+                String::from("axios"),
+                [String::from("web/orval.config.ts")].into(),
+            ),
+            (
+                String::from("msw"),
+                [String::from("web/orval.config.ts")].into(),
+            ),
+            (
+                String::from("orval"),
+                [String::from("web/orval.config.ts")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("swr")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_prod_deps_used_only_in_dev() {
+    init();
+    let path = get_module_path("dev_only");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let dev_globs = [String::from("**/*.test.js"), String::from("stories/**")];
+    assert_eq!(actual.prod_deps_used_only_in_dev(&dev_globs), vec!["msw"]);
+    assert_eq!(
+        actual.prod_deps_used_only_in_dev(&[String::from("stories/**")]),
+        Vec::<&str>::new()
+    );
+}
+
+#[test]
+fn test_use_directive() {
+    init();
+    let path = get_module_path("use_directive");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("other-pkg"), [String::from("index.js")].into()),
+            (
+                String::from("plugin-x"),
+                [String::from("plugins.ts")].into(),
+            ),
+            (String::from("some-pkg"), [String::from("index.js")].into()),
+            (
+                String::from("undeclared-pkg"),
+                [String::from("plugins.ts")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("undeclared-pkg"),
+            [String::from("plugins.ts")].into(),
+        )]),
+        unused_dependencies: [String::from("unused-pkg")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_optional_peer_dep() {
+    init();
+    let path = get_module_path("optional_peer_dep");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("host"), [String::from("index.js")].into()),
+            (
+                String::from("installed-peer"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("absent-peer")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_scan_extensions() {
+    init();
+    let path = get_module_path("scan_extensions");
+
+    let config = Config::new(path)
+        .with_scan_extensions(Some([String::from("ts")].into()))
+        .with_specials(vec![String::from("graphql-codegen")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@graphql-codegen/cli"),
+                [String::from("codegen.yml")].into(),
+            ),
+            (String::from("lodash"), [String::from("index.ts")].into()),
+        ]),
+        unused_dependencies: [String::from("react")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_asset_imports() {
+    init();
+    let path = get_module_path("asset_imports");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@fontsource/inter"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("@scope/icons"),
+                [String::from("icons.ts")].into(),
+            ),
+            (
+                String::from("file-icons"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("images-pkg"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_for_package_dir() {
+    init();
+    let path = get_module_path("missing_nested").join("nested");
+
+    let expected = || ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("nested-missing-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("nested-missing-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    let config = Config::for_package_dir(path.clone());
+    assert_eq!(config.get_directory(), path);
+    let actual = Checker::new(config).check_package().unwrap();
+    assert_result(actual, expected());
+
+    let config = Config::for_package_dir(path.join("package.json"));
+    assert_eq!(config.get_directory(), path);
+    let actual = Checker::new(config).check_package().unwrap();
+    assert_result(actual, expected());
+}
+
+#[test]
+fn test_plugin_arrays() {
+    init();
+    let path = get_module_path("plugin_arrays");
+
+    let config = Config::new(path).with_plugin_arrays(vec![(
+        String::from("app.config.js"),
+        vec![String::from("plugins"), String::from("presets")],
+    )]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/plugin-b"),
+                [String::from("app.config.js")].into(),
+            ),
+            (
+                String::from("plugin-a"),
+                [String::from("app.config.js")].into(),
+            ),
+            (
+                String::from("preset-a"),
+                [String::from("app.config.js")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("other-pkg")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_class_members() {
+    init();
+    let path = get_module_path("class_members");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: [
+            "field-dep",
+            "getter-dep",
+            "method-dep",
+            "shorthand-dep",
+            "static-block-dep",
+            "static-field-dep",
+        ]
+        .into_iter()
+        .map(|dependency| (String::from(dependency), [String::from("index.js")].into()))
+        .collect(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_missing_dependencies_brief() {
+    init();
+    let path = get_module_path("package");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = BTreeMap::from([
+        ("@package/first2", "src/rootFile.ts"),
+        ("@package/first3", "src/rootFile.ts"),
+        ("@packageRoot/first1", "src/rootFile.ts"),
+        ("@packageSubDir/first1", "src/subDir/subDirFile.ts"),
+        (
+            "@packageSubSubDir/first1",
+            "src/subDir/subSubDir/subSubDirFile.ts",
+        ),
+        ("react", "src/rootFile.ts"),
+    ]);
+
+    assert_eq!(actual.missing_dependencies_brief(), expected);
+}
+
+#[test]
+fn test_duplicate_installs() {
+    init();
+    let path = get_module_path("duplicate_installs");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = BTreeMap::from([(
+        String::from("lodash"),
+        vec![
+            (
+                PathBuf::from("node_modules/legacy/node_modules/lodash"),
+                String::from("3.10.1"),
+            ),
+            (
+                PathBuf::from("node_modules/lodash"),
+                String::from("4.17.21"),
+            ),
+            (
+                PathBuf::from("node_modules/plugin/node_modules/lodash"),
+                String::from("4.17.20"),
+            ),
+        ],
+    )]);
+
+    assert_eq!(actual.get_duplicate_installs(), expected);
+}
+
+#[test]
+fn test_unused_peer_dependencies() {
+    init();
+    let path = get_module_path("unused_peer_deps");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(actual.get_unused_peer_dependencies(), vec!["typescript"]);
+}
+
+#[test]
+fn test_non_registry_dependencies() {
+    init();
+    let path = get_module_path("non_registry_deps");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(
+        actual.get_non_registry_dependencies(),
+        vec![
+            ("bar", "github:user/bar"),
+            ("baz", "user/baz#v1.0.0"),
+            ("foo", "file:../foo"),
+            ("git-dep", "git+https://github.com/user/git-dep.git"),
+            ("linked", "link:../linked"),
+            ("tarball", "https://example.com/tarball.tgz"),
+        ]
+    );
+
+    // dependencies are matched by names regardless of their versions
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("bar"), [String::from("index.js")].into()),
+            (String::from("foo"), [String::from("index.js")].into()),
+            (String::from("qux"), [String::from("index.js")].into()),
+        ]),
+        unused_dependencies: [
+            String::from("alias"),
+            String::from("baz"),
+            String::from("tarball"),
+        ]
+        .into(),
+        unused_dev_dependencies: [
+            String::from("git-dep"),
+            String::from("linked"),
+            String::from("next-tag"),
+        ]
+        .into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_registry_specifiers() {
+    init();
+    let path = get_module_path("registry_specifiers");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // url imports aren't dependencies
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scope/schema"),
+                [String::from("index.ts")].into(),
+            ),
+            (
+                String::from("@std/assert"),
+                [String::from("index.ts")].into(),
+            ),
+            (String::from("@std/path"), [String::from("index.ts")].into()),
+            (String::from("chalk"), [String::from("index.ts")].into()),
+            (String::from("express"), [String::from("index.ts")].into()),
+            (String::from("react"), [String::from("index.ts")].into()),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_orphan_type_packages() {
+    init();
+    let path = get_module_path("orphan_types");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(
+        actual.get_orphan_type_packages(),
+        vec![
+            ("@types/babel__core", OrphanTypeReason::BaseAbsent),
+            ("@types/express", OrphanTypeReason::BaseAbsent),
+            ("@types/lodash", OrphanTypeReason::BaseUnused),
+        ]
+    );
+}
+
+#[test]
+fn test_package_is_private() {
+    init();
+
+    let config = Config::new(get_module_path("private_package"));
+    let actual = Checker::new(config).check_package().unwrap();
+    assert!(actual.package_is_private());
+
+    let config = Config::new(get_module_path("good"));
+    let actual = Checker::new(config).check_package().unwrap();
+    assert!(!actual.package_is_private());
+}
+
+#[test]
+fn test_namespace_exports() {
+    init();
+    let path = get_module_path("namespace_exports");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("lodash"), [String::from("index.js")].into()),
+            (String::from("some-pkg"), [String::from("index.js")].into()),
+            (String::from("star-pkg"), [String::from("index.js")].into()),
+            (
+                String::from("ts-import-pkg"),
+                [String::from("types.ts")].into(),
+            ),
+            (
+                String::from("ts-namespace-pkg"),
+                [String::from("types.ts")].into(),
+            ),
+            (
+                String::from("ts-type-pkg"),
+                [String::from("types.ts")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_preserve_manifest_order() {
+    init();
+    let path = get_module_path("manifest_order");
+
+    let config = Config::new(path.clone());
+    let actual = Checker::new(config).check_package().unwrap();
+    assert_eq!(
+        actual.get_unused_dependencies_sorted(),
+        vec!["classnames", "moment", "zod"]
+    );
+
+    let config = Config::new(path).with_preserve_manifest_order(true);
+    let actual = Checker::new(config).check_package().unwrap();
+    assert_eq!(
+        actual.get_unused_dependencies_sorted(),
+        vec!["zod", "moment", "classnames"]
+    );
+}
+
+#[test]
+fn test_git_tracked_only() {
+    init();
+    let path = get_module_path("git_tracked");
+
+    let is_tracked = Command::new("git")
+        .args(["ls-files", "--error-unmatch", "index.js"])
+        .current_dir(&path)
+        .output()
+        .map_or(false, |output| output.status.success());
+
+    if !is_tracked {
+        // the fixture isn't inside of a git checkout
+        return;
+    }
+
+    struct UntrackedFile(PathBuf);
+
+    impl Drop for UntrackedFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    let untracked_file = UntrackedFile(path.join("untracked.js"));
+    fs::write(&untracked_file.0, "require('untracked-dep');").unwrap();
+
+    let config = Config::new(path.clone());
+    let actual = Checker::new(config).check_package().unwrap();
+    assert!(actual.using_dependencies.contains_key("untracked-dep"));
+
+    let config = Config::new(path).with_git_tracked_only(true);
+    let actual = Checker::new(config).check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("tracked-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("untracked-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_optional_import() {
+    init();
+    let path = get_module_path("optional_import");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("chalk"), [String::from("index.mjs")].into()),
+            (
+                String::from("fsevents"),
+                [String::from("watcher.cjs")].into(),
+            ),
+            (
+                String::from("optional-pkg"),
+                [String::from("index.mjs")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    // absent optional dependencies aren't reported as uninstalled either
+    assert_eq!(actual.get_uninstalled_dependencies(), vec!["chalk"]);
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_create_require() {
+    init();
+    let path = get_module_path("create_require");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("chalk"), [String::from("index.mjs")].into()),
+            (String::from("lodash"), [String::from("index.mjs")].into()),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_unresolved_internal_scopes() {
+    init();
+    let path = get_module_path("unresolved_internal");
+
+    let config = Config::new(path).with_unresolved_internal_scopes(vec![String::from("@my-org")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // installed packages of internal scopes are still missing in `package.json`
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("lodash"), [String::from("index.js")].into()),
+            (
+                String::from("@my-org/ui"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("@my-org/utils"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("@other-org/analytics"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([
+            (
+                String::from("@my-org/utils"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("@other-org/analytics"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_exports_issues() {
+    init();
+    let path = get_module_path("exports_issues");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let issue = |field: &str, target: &str, kind| ExportsIssue {
+        field: String::from(field),
+        target: String::from(target),
+        kind,
+    };
+
+    assert_eq!(
+        actual.get_exports_issues(),
+        vec![
+            issue(
+                "exports[\".\"].require",
+                "./lib/index.cjs",
+                ExportsIssueKind::MissingTarget
+            ),
+            issue(
+                "exports[\"./feature\"].default",
+                "lib/feature.js",
+                ExportsIssueKind::NonRelativeTarget
+            ),
+            issue(
+                "exports[\"./feature\"].types",
+                "./lib/feature.js",
+                ExportsIssueKind::NonDeclarationTypes
+            ),
+            issue(
+                "exports[\"./icons/*\"]",
+                "./lib/icons/*.svg",
+                ExportsIssueKind::MissingTarget
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_concatenated_require() {
+    init();
+    let path = get_module_path("concatenated_require");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("date-fns"), [String::from("index.js")].into()),
+            (
+                String::from("@formatjs/intl-pluralrules"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("highlight.js"), String::from("moment")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_scan_hidden() {
+    init();
+    let path = get_module_path("scan_hidden");
+
+    let config = Config::new(path.clone());
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dev_dependencies: [String::from("dotenv"), String::from("eslint-plugin-import")]
+            .into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+
+    let config = Config::new(path).with_scan_hidden(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("lodash"), [String::from("index.js")].into()),
+            (
+                String::from("dotenv"),
+                [String::from(".config/setup.js")].into(),
+            ),
+            (
+                String::from("eslint-plugin-import"),
+                [String::from(".eslintrc.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_loader_hooks() {
+    init();
+    let path = get_module_path("loader_hooks");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("ts-node"),
+                [String::from("register.js")].into(),
+            ),
+            (
+                String::from("@swc-node/register"),
+                [String::from("register.js")].into(),
+            ),
+            (String::from("tsx"), [String::from("legacy.cjs")].into()),
+        ]),
+        unused_dev_dependencies: [String::from("unused-loader")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_tsconfig_paths() {
+    init();
+    let path = get_module_path("tsconfig_paths");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // aliases of missing targets fall back to module resolution
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@fortawesome/free-solid-svg-icons"),
+                [String::from("src/index.ts")].into(),
+            ),
+            (
+                String::from("@generated/schema"),
+                [String::from("src/index.ts")].into(),
+            ),
+            (
+                String::from("lodash-es"),
+                [String::from("src/index.ts")].into(),
+            ),
+            (String::from("react"), [String::from("src/index.ts")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("@generated/schema"),
+            [String::from("src/index.ts")].into(),
+        )]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_to_text() {
+    let mut result = CheckerResult::default();
+    result.missing_dependencies = BTreeMap::from([(
+        String::from("react"),
+        [String::from("src/b.js"), String::from("src/a.js")].into(),
+    )]);
+    result.unused_dependencies = [String::from("lodash"), String::from("axios")].into();
+
+    let expected = [
+        "Missing dependencies:",
+        "  react (src/a.js)",
+        "Unused dependencies:",
+        "  axios",
+        "  lodash",
+        "Unused devDependencies: none",
+        "",
+    ]
+    .join("\n");
+
+    assert_eq!(to_text(&result), expected);
+}
+
+#[test]
+fn test_fail_on_parse_error() {
+    init();
+    let path = get_module_path("bad_js");
+
+    let checker = Checker::new(Config::new(path.clone()));
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(actual.get_parse_errors(), [String::from("index.js")]);
+    assert_eq!(actual.summary().files_scanned, 0);
+
+    let config = Config::new(path).with_fail_on_parse_error(true);
+    let result = Checker::new(config).check_package();
+    assert!(matches!(
+        result,
+        Err(DepcheckError::ParseSource { files, .. }) if files == [String::from("index.js")]
+    ));
+}
+
+#[test]
+fn test_ts_module_variants() {
+    init();
+    let path = get_module_path("ts_module_variants");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("chalk"), [String::from("index.mts")].into()),
+            (
+                String::from("eslint"),
+                [String::from("types/lint.d.cts")].into(),
+            ),
+            (String::from("fs-extra"), [String::from("cli.cts")].into()),
+            (
+                String::from("prettier"),
+                [String::from("types/format.d.mts")].into(),
+            ),
+            (String::from("yargs"), [String::from("cli.cts")].into()),
+        ]),
+        unused_dev_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_eq!(actual.get_parse_errors(), Vec::<String>::new());
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_check_with_dependencies() {
+    init();
+    let path = get_module_path("mjs");
+
+    let checker = Checker::new(Config::new(path));
+    let declared = BTreeMap::from([
+        (String::from("optimist"), String::from("~0.6.0")),
+        (String::from("bar"), String::from("^1.0.0")),
+    ]);
+    let actual = checker.check_with_dependencies(declared).unwrap();
+
+    // `foo` of `package.json` would be missing once removed
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("optimist"), [String::from("index.mjs")].into()),
+            (String::from("foo"), [String::from("index.mjs")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("foo"),
+            [String::from("index.mjs")].into(),
+        )]),
+        unused_dependencies: [String::from("bar")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_ignore_inheritance() {
+    init();
+    let path = get_module_path("ignore_inheritance");
+    let directories = vec![path.join("packages/app"), path.join("packages/lib")];
+
+    // the root config is the base of every workspace check
+    let config = Config::new(path)
+        .with_ignore_patterns(vec![String::from("fixtures")])
+        .with_ignore_path(Some(PathBuf::from(".depcheckignore")));
+    let checker = Checker::new(config);
+    let actual = checker.check_packages(directories).unwrap();
+
+    let (_, app) = &actual[0];
+    assert_eq!(
+        app.using_dependencies,
+        BTreeMap::from([
+            (
+                String::from("axios"),
+                [String::from("generated/client.js")].into()
+            ),
+            (String::from("react"), [String::from("src/index.js")].into()),
+        ])
+    );
+    assert_eq!(app.unused_dev_dependencies, [String::from("msw")].into());
+
+    let (_, lib) = &actual[1];
+    assert_eq!(
+        lib.using_dependencies,
+        BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("src/index.js")].into()
+        )])
+    );
+    assert_eq!(lib.unused_dependencies, [String::from("protobufjs")].into());
+}
+
+#[test]
+fn test_provide_plugin() {
+    init();
+    let path = get_module_path("provide_plugin");
+
+    let config = Config::new(path.clone());
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // provided packages are unused while the special is disabled
+    assert_eq!(
+        actual.get_unused_dependencies_sorted(),
+        vec!["jquery", "lodash", "process"]
+    );
+
+    let config = Config::new(path).with_specials(vec![String::from("provide-plugin")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("jquery"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("lodash"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("process"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("webpack"),
+                [String::from("webpack.config.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_reexported_only_dependencies() {
+    init();
+    let path = get_module_path("reexported_only");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // `react` is re-exported as well as imported by `app.js`
+    assert_eq!(
+        actual.reexported_only_dependencies(),
+        BTreeSet::from(["lodash", "zod"])
+    );
+    assert!(actual.unused_dependencies.is_empty());
+}
+
+#[test]
+fn test_dangling_hoisted_scopes() {
+    init();
+    let path = get_module_path("dangling_workspaces");
+
+    // `apps/*` and `tools/scripts` don't exist and `packages/docs` has no package json
+    let config = Config::new(path)
+        .with_ignore_patterns(vec![String::from("packages")])
+        .with_hoisted_scopes(vec![
+            String::from("packages/*"),
+            String::from("apps/*"),
+            String::from("tools/scripts"),
+        ]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        actual.get_warnings(),
+        [
+            String::from("hoisted scope apps/* matches no directories"),
+            String::from("hoisted scope tools/scripts matches no directories"),
+            String::from("failed to read package json of hoisted scope packages/docs"),
+        ]
+    );
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_jsx_dynamic_import() {
+    init();
+    let path = get_module_path("jsx_dynamic_import");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // imports of attributes and children are found in both `.jsx` and `.tsx` files
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("chart-lib"),
+                [String::from("routes.jsx")].into(),
+            ),
+            (
+                String::from("editor-lib"),
+                [String::from("lazy.tsx")].into(),
+            ),
+            (String::from("react"), [String::from("lazy.tsx")].into()),
+            (
+                String::from("react-router-dom"),
+                [String::from("routes.jsx")].into(),
+            ),
+            (
+                String::from("some-pkg"),
+                [String::from("routes.jsx")].into(),
+            ),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_vitest() {
+    init();
+    let path = get_module_path("vitest");
+
+    let config = Config::new(path.clone());
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // packages named by strings of the config are unused while the special is disabled
+    assert_eq!(
+        actual.unused_dev_dependencies,
+        [
+            String::from("@vitest/coverage-v8"),
+            String::from("jsdom"),
+            String::from("unused-dev"),
+            String::from("vitest-sonar-reporter"),
+        ]
+        .into()
+    );
+
+    let config = Config::new(path).with_specials(vec![String::from("vitest")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@testing-library/jest-dom"),
+                [String::from("test/setup.ts")].into(),
+            ),
+            (
+                String::from("@vitest/coverage-v8"),
+                [String::from("vitest.config.ts")].into(),
+            ),
+            (
+                String::from("jsdom"),
+                [String::from("vitest.config.ts")].into(),
+            ),
+            (
+                String::from("vitest"),
+                [String::from("vitest.config.ts")].into(),
+            ),
+            (
+                String::from("vitest-sonar-reporter"),
+                [String::from("vitest.config.ts")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-dev")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_manifest_paths() {
+    init();
+    let path = get_module_path("manifest_overlay");
+
+    let config = Config::new(path.clone());
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    assert_eq!(
+        actual.missing_dependencies.keys().collect::<Vec<_>>(),
+        vec!["express", "pino"]
+    );
+
+    // dependencies of the overlay are declared as well
+    let config = Config::new(path.clone())
+        .with_manifest_paths(vec![PathBuf::from("package.production.json")]);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("express"), [String::from("index.js")].into()),
+            (String::from("lodash"), [String::from("index.js")].into()),
+            (String::from("pino"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("pino"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("react")].into(),
+        unused_dev_dependencies: [String::from("jest")].into(),
+    };
+
+    assert_result(actual, expected);
+
+    let config = Config::new(path).with_manifest_paths(vec![PathBuf::from("missing.json")]);
+    let checker = Checker::new(config);
+
+    assert!(matches!(
+        checker.check_package(),
+        Err(DepcheckError::Io { .. })
+    ));
+}
+
+#[test]
+fn test_legacy_modules() {
+    init();
+    let path = get_module_path("legacy_modules");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // pseudo dependencies like `exports` and loader plugin resources aren't packages
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("backbone"), [String::from("amd.js")].into()),
+            (
+                String::from("chart-lib"),
+                [String::from("system.js")].into(),
+            ),
+            (
+                String::from("jquery"),
+                [String::from("amd.js"), String::from("named.js")].into(),
+            ),
+            (String::from("underscore"), [String::from("amd.js")].into()),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_loose_version_dependencies() {
+    init();
+    let path = get_module_path("loose_versions");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // ranges of a major version and of the workspace protocol aren't loose
+    assert_eq!(
+        actual.get_loose_version_dependencies(),
+        vec![
+            ("alias", "npm:other@latest"),
+            ("any", "*"),
+            ("empty", ""),
+            ("newest", "latest"),
+            ("wildcard", "x"),
+        ]
+    );
+}
+
+#[test]
+fn test_module_augmentation() {
+    init();
+    let path = get_module_path("module_augmentation");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    // augmented packages are used like type imports, so undeclared ones aren't missing,
+    // `declare module` of source files like `src/index.ts` doesn't use packages
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@types/express-serve-static-core"),
+                [String::from("types/express.d.ts")].into(),
+            ),
+            (String::from("vue"), [String::from("types/vue.d.ts")].into()),
+        ]),
+        unused_dependencies: [String::from("unused-dep")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_run_special() {
+    init();
+    let path = get_module_path("vitest");
+
+    // the special runs even though it isn't enabled
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.run_special("vitest").unwrap();
+
+    let expected = SpecialReport {
+        special: String::from("vitest"),
+        dependencies: BTreeMap::from([
+            (
+                String::from("@vitest/coverage-v8"),
+                [String::from("vitest.config.ts")].into(),
+            ),
+            (
+                String::from("jsdom"),
+                [String::from("vitest.config.ts")].into(),
+            ),
+            (
+                String::from("vitest-sonar-reporter"),
+                [String::from("vitest.config.ts")].into(),
+            ),
+        ]),
+        entry_files: [String::from("test/setup.ts")].into(),
+    };
+
+    assert_eq!(actual, expected);
+
+    assert!(matches!(
+        checker.run_special("unknown"),
+        Err(DepcheckError::UnknownSpecial { .. })
+    ));
+}
+
+#[test]
+fn test_renovate_rc() {
+    init();
+    let path = get_module_path("renovate_rc");
+
+    let config = Config::new(path)
+        .with_specials(vec![String::from("renovate")])
+        .with_scan_hidden(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("renovate-config-shared"),
+            [String::from(".renovaterc")].into(),
+        )]),
+        unused_dev_dependencies: [String::from("renovate-config-unused")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_babel_rc() {
+    init();
+    let path = get_module_path("babel_rc");
+
+    let config = Config::new(path)
+        .with_specials(vec![String::from("babel")])
+        .with_scan_hidden(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@babel/preset-env"),
+                [String::from(".babelrc")].into(),
+            ),
+            (
+                String::from("babel-plugin-istanbul"),
+                [String::from("test/.babelrc.json")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("babel-plugin-unused")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_deserialize_report_without_expired_baseline() {
+    // results serialized before baselines expired have no `expiredBaseline`
+    let json = serde_json::json!({
+        "usingDependencies": { "react": ["index.js"] },
+        "missingDependencies": {},
+        "unusedDependencies": ["lodash"],
+        "unusedDevDependencies": []
+    });
+
+    let actual = serde_json::from_value::<CheckerResult>(json).unwrap();
+
+    assert_eq!(actual.get_expired_baseline(), &Baseline::default());
+    assert_eq!(actual.get_unused_dependencies_sorted(), vec!["lodash"]);
+}
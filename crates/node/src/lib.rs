@@ -18,6 +18,23 @@ pub struct Options {
     pub ignore_matches: Option<Vec<String>>,
     pub skip_missing: Option<bool>,
     pub ignore_path: Option<String>,
+    pub specials: Option<Vec<String>>,
+    pub include_modules: Option<Vec<String>>,
+    pub single_threaded: Option<bool>,
+    pub follow_symlinks: Option<bool>,
+    pub suppress_common_devtools: Option<bool>,
+    pub embedded_script_extensions: Option<Vec<String>>,
+    pub lockfile: Option<String>,
+    pub manifest_paths: Option<Vec<String>>,
+    pub absolute_paths: Option<bool>,
+    pub hoisted_scopes: Option<Vec<String>>,
+    pub manifest_package_fields: Option<Vec<String>>,
+    pub scan_extensions: Option<Vec<String>>,
+    pub plugin_arrays: Option<HashMap<String, Vec<String>>>,
+    pub git_tracked_only: Option<bool>,
+    pub scan_hidden: Option<bool>,
+    pub fail_on_parse_error: Option<bool>,
+    pub unresolved_internal_scopes: Option<Vec<String>>,
     pub verbose: Option<u32>,
 }
 
@@ -36,6 +53,7 @@ impl From<CheckerResult> for DepcheckResult {
             missing_dependencies,
             unused_dependencies,
             unused_dev_dependencies,
+            ..
         } = result;
 
         let using_dependencies = using_dependencies
@@ -61,7 +79,7 @@ impl From<CheckerResult> for DepcheckResult {
 }
 
 #[napi]
-pub fn depcheck(path: String, options: Option<Options>) -> DepcheckResult {
+pub fn depcheck(path: String, options: Option<Options>) -> napi::Result<DepcheckResult> {
     let path = PathBuf::from(path);
 
     let mut config = config::Config::new(path);
@@ -83,6 +101,76 @@ pub fn depcheck(path: String, options: Option<Options>) -> DepcheckResult {
             config = config.with_skip_missing(skip_missing);
         }
 
+        if let Some(specials) = options.specials {
+            config = config.with_specials(specials);
+        }
+
+        if let Some(include_modules) = options.include_modules {
+            config = config.with_include_modules(include_modules);
+        }
+
+        if let Some(single_threaded) = options.single_threaded {
+            config = config.with_single_threaded(single_threaded);
+        }
+
+        if let Some(follow_symlinks) = options.follow_symlinks {
+            config = config.with_follow_symlinks(follow_symlinks);
+        }
+
+        if let Some(suppress_common_devtools) = options.suppress_common_devtools {
+            config = config.with_suppress_common_devtools(suppress_common_devtools);
+        }
+
+        if let Some(embedded_script_extensions) = options.embedded_script_extensions {
+            config = config.with_embedded_script_extensions(embedded_script_extensions);
+        }
+
+        if let Some(lockfile) = options.lockfile {
+            let lockfile = PathBuf::from(lockfile);
+            config = config.with_dependency_source(config::DependencySource::Lockfile(lockfile));
+        }
+
+        if let Some(manifest_paths) = options.manifest_paths {
+            let manifest_paths = manifest_paths.into_iter().map(PathBuf::from).collect();
+            config = config.with_manifest_paths(manifest_paths);
+        }
+
+        if let Some(true) = options.absolute_paths {
+            config = config.with_path_base(config::PathBase::Absolute);
+        }
+
+        if let Some(hoisted_scopes) = options.hoisted_scopes {
+            config = config.with_hoisted_scopes(hoisted_scopes);
+        }
+
+        if let Some(manifest_package_fields) = options.manifest_package_fields {
+            config = config.with_manifest_package_fields(manifest_package_fields);
+        }
+
+        if let Some(scan_extensions) = options.scan_extensions {
+            config = config.with_scan_extensions(Some(scan_extensions.into_iter().collect()));
+        }
+
+        if let Some(plugin_arrays) = options.plugin_arrays {
+            config = config.with_plugin_arrays(plugin_arrays.into_iter().collect());
+        }
+
+        if let Some(git_tracked_only) = options.git_tracked_only {
+            config = config.with_git_tracked_only(git_tracked_only);
+        }
+
+        if let Some(scan_hidden) = options.scan_hidden {
+            config = config.with_scan_hidden(scan_hidden);
+        }
+
+        if let Some(fail_on_parse_error) = options.fail_on_parse_error {
+            config = config.with_fail_on_parse_error(fail_on_parse_error);
+        }
+
+        if let Some(unresolved_internal_scopes) = options.unresolved_internal_scopes {
+            config = config.with_unresolved_internal_scopes(unresolved_internal_scopes);
+        }
+
         let verbose = options.verbose.unwrap_or(0);
         let verbose = match verbose {
             0 => LevelFilter::Error,
@@ -97,7 +185,10 @@ pub fn depcheck(path: String, options: Option<Options>) -> DepcheckResult {
         config = config.with_ignore_path(ignore_path);
     }
 
-    let result = Checker::new(config).check_package().unwrap();
+    // errors like unparsable files of `failOnParseError` are thrown as JS errors
+    let result = Checker::new(config)
+        .check_package()
+        .map_err(|error| napi::Error::from_reason(error.to_string()))?;
 
-    result.into()
+    Ok(result.into())
 }
@@ -9,6 +9,23 @@ export interface Options {
   ignoreMatches?: Array<string>
   skipMissing?: boolean
   ignorePath?: string
+  specials?: Array<string>
+  includeModules?: Array<string>
+  singleThreaded?: boolean
+  followSymlinks?: boolean
+  suppressCommonDevtools?: boolean
+  embeddedScriptExtensions?: Array<string>
+  lockfile?: string
+  manifestPaths?: Array<string>
+  absolutePaths?: boolean
+  hoistedScopes?: Array<string>
+  manifestPackageFields?: Array<string>
+  scanExtensions?: Array<string>
+  pluginArrays?: Record<string, Array<string>>
+  gitTrackedOnly?: boolean
+  scanHidden?: boolean
+  failOnParseError?: boolean
+  unresolvedInternalScopes?: Array<string>
   verbose?: number
 }
 export interface DepcheckResult {